@@ -0,0 +1,165 @@
+// Allocation accounting for `--features alloc-stats`. The feature installs a
+// global allocator that counts, per thread, the allocations made and the
+// bytes live, so the runner can measure each stage of a day even while other
+// days run on other threads. Without the feature every measurement is zero.
+
+/// Whether allocations are being counted in this build.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// What a stretch of code allocated on its thread.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Allocations made, counting each reallocation as one.
+    pub allocations: u64,
+    /// Bytes requested by those allocations.
+    pub bytes: u64,
+    /// Most bytes live at once, above what was live at the start.
+    pub peak: u64,
+}
+
+/// Run `f`, counting what it allocates on this thread.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let (start, outer_peak) = counting::start();
+        let value = f();
+        (value, counting::finish(start, outer_peak))
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (f(), AllocStats::default())
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    use super::AllocStats;
+
+    #[derive(Debug, Clone, Copy)]
+    pub struct Counts {
+        allocations: u64,
+        bytes: u64,
+        /// Can go negative when freeing memory allocated on another thread.
+        live: i64,
+        peak: i64,
+    }
+
+    thread_local! {
+        // Const-initialised and without a destructor, so the allocator can
+        // use it without allocating itself.
+        static COUNTS: Cell<Counts> = const {
+            Cell::new(Counts {
+                allocations: 0,
+                bytes: 0,
+                live: 0,
+                peak: 0,
+            })
+        };
+    }
+
+    fn update(f: impl FnOnce(&mut Counts)) {
+        // Fails only while the thread is being torn down.
+        let _ = COUNTS.try_with(|counts| {
+            let mut c = counts.get();
+            f(&mut c);
+            c.peak = c.peak.max(c.live);
+            counts.set(c);
+        });
+    }
+
+    /// The counts so far, and the peak to restore afterwards. The peak is
+    /// reset to what is live now, so the measured peak starts from here.
+    pub fn start() -> (Counts, i64) {
+        let start = COUNTS.with(Cell::get);
+        COUNTS.with(|counts| {
+            counts.set(Counts {
+                peak: start.live,
+                ..start
+            })
+        });
+        (start, start.peak)
+    }
+
+    pub fn finish(start: Counts, outer_peak: i64) -> AllocStats {
+        let end = COUNTS.with(Cell::get);
+        COUNTS.with(|counts| {
+            counts.set(Counts {
+                peak: end.peak.max(outer_peak),
+                ..end
+            })
+        });
+        AllocStats {
+            allocations: end.allocations - start.allocations,
+            bytes: end.bytes - start.bytes,
+            peak: (end.peak - start.live).max(0) as u64,
+        }
+    }
+
+    pub struct Counting;
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            update(|c| {
+                c.allocations += 1;
+                c.bytes += layout.size() as u64;
+                c.live += layout.size() as i64;
+            });
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            update(|c| {
+                c.allocations += 1;
+                c.bytes += layout.size() as u64;
+                c.live += layout.size() as i64;
+            });
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            update(|c| c.live -= layout.size() as i64);
+            System.dealloc(ptr, layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            update(|c| {
+                c.allocations += 1;
+                c.bytes += new_size as u64;
+                c.live += new_size as i64 - layout.size() as i64;
+            });
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: Counting = Counting;
+}
+
+#[cfg(all(test, feature = "alloc-stats"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_allocations_and_peak() {
+        let (_, stats) = measure(|| {
+            let big = vec![0u8; 4096];
+            drop(big);
+            let small: Vec<u8> = Vec::with_capacity(100);
+            small
+        });
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.bytes, 4196);
+        assert_eq!(stats.peak, 4096);
+
+        let (_, outer) = measure(|| {
+            let kept = vec![0u64; 100];
+            let (_, inner) = measure(|| vec![0u8; 10]);
+            assert_eq!(inner.peak, 10);
+            kept
+        });
+        assert_eq!((outer.allocations, outer.peak), (2, 810));
+    }
+}
@@ -0,0 +1,308 @@
+// Recorded answers for the real inputs, one `answers/<year>/dayNN.toml` per day,
+// along with what `aoc submit` has learned from wrong answers:
+//
+//     retry_after = 1733130000
+//     part1 = "1234"
+//     part2_rejected = ["17", "99999"]
+//     part2_too_low = 17
+//     part2_too_high = 99999
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::input;
+
+/// Environment variable naming the directory that holds `<year>/dayNN.toml`
+/// files.
+pub const ANSWERS_DIR_VAR: &str = "AOC_ANSWERS_DIR";
+
+/// Directory used when `AOC_ANSWERS_DIR` is not set.
+pub const DEFAULT_ANSWERS_DIR: &str = "answers";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    /// Wrong answers given for each part.
+    pub attempts: [Attempts; 2],
+    /// Unix time before which the site will refuse another submission.
+    pub retry_after: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Attempts {
+    /// Answers the site rejected, never to be submitted again.
+    pub rejected: Vec<String>,
+    /// The largest answer known to be too low.
+    pub too_low: Option<i64>,
+    /// The smallest answer known to be too high.
+    pub too_high: Option<i64>,
+}
+
+impl Answers {
+    pub fn dir() -> PathBuf {
+        std::env::var_os(ANSWERS_DIR_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| DEFAULT_ANSWERS_DIR.into())
+    }
+
+    pub fn path(dir: &Path, year: u16, day: u8) -> PathBuf {
+        dir.join(year.to_string()).join(format!("day{day:02}.toml"))
+    }
+
+    /// Move answer files from the flat `dir/dayN.toml` layout into the
+    /// folder of the year they were recorded for. Returns the moves made.
+    pub fn migrate_legacy(dir: &Path) -> Result<Vec<(PathBuf, PathBuf)>, AnswersError> {
+        input::migrate_legacy(dir, "toml", |day| Self::path(dir, input::LEGACY_YEAR, day))
+            .map_err(|e| AnswersError::Io(dir.to_path_buf(), e))
+    }
+
+    /// Load the answers for `day` of `year`; a missing file means nothing is
+    /// recorded yet.
+    pub fn load(dir: &Path, year: u16, day: u8) -> Result<Self, AnswersError> {
+        let path = Self::path(dir, year, day);
+        match std::fs::read_to_string(&path) {
+            Ok(text) => {
+                Self::parse(&text).map_err(|(line, msg)| AnswersError::Parse { path, line, msg })
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(AnswersError::Io(path, e)),
+        }
+    }
+
+    pub fn save(&self, dir: &Path, year: u16, day: u8) -> Result<(), AnswersError> {
+        let path = Self::path(dir, year, day);
+        let dir = path
+            .parent()
+            .expect("answer files are inside their year's folder");
+        std::fs::create_dir_all(dir).map_err(|e| AnswersError::Io(dir.to_path_buf(), e))?;
+        std::fs::write(&path, self.to_string()).map_err(|e| AnswersError::Io(path, e))
+    }
+
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set_part(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part1 = Some(answer),
+            _ => self.part2 = Some(answer),
+        }
+    }
+
+    pub fn attempts(&self, part: u8) -> &Attempts {
+        &self.attempts[usize::from(part == 2)]
+    }
+
+    pub fn attempts_mut(&mut self, part: u8) -> &mut Attempts {
+        &mut self.attempts[usize::from(part == 2)]
+    }
+
+    /// Parse the `key = value` subset of TOML used by answer files. Errors
+    /// carry the 1-based line number.
+    fn parse(text: &str) -> Result<Self, (usize, String)> {
+        let mut answers = Self::default();
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or((i + 1, "expected `key = value`".to_string()))?;
+            let (key, value) = (key.trim(), value.trim());
+            let invalid = || (i + 1, "invalid value".to_string());
+            let unknown = || Err((i + 1, format!("unknown key `{key}`")));
+            let (part, field) = match key.split_once('_') {
+                _ if key == "retry_after" => {
+                    answers.retry_after = Some(parse_int(value).ok_or_else(invalid)?);
+                    continue;
+                }
+                Some(("part1", field)) => (1, field),
+                Some(("part2", field)) => (2, field),
+                None if key == "part1" => (1, ""),
+                None if key == "part2" => (2, ""),
+                _ => return unknown(),
+            };
+
+            let attempts = answers.attempts_mut(part);
+            match field {
+                "" => answers.set_part(part, parse_value(value).ok_or_else(invalid)?),
+                "rejected" => attempts.rejected = parse_list(value).ok_or_else(invalid)?,
+                "too_low" => attempts.too_low = Some(parse_int(value).ok_or_else(invalid)?),
+                "too_high" => attempts.too_high = Some(parse_int(value).ok_or_else(invalid)?),
+                _ => return unknown(),
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+/// A quoted string or a bare integer.
+fn parse_value(value: &str) -> Option<String> {
+    if let Some(s) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        return (!s.contains('"')).then(|| s.to_string());
+    }
+
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    (!digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())).then(|| value.to_string())
+}
+
+fn parse_int<T: FromStr>(value: &str) -> Option<T> {
+    parse_value(value)?.parse().ok()
+}
+
+/// A bracketed, comma-separated list of values.
+fn parse_list(value: &str) -> Option<Vec<String>> {
+    let mut rest = value.strip_prefix('[')?.strip_suffix(']')?.trim();
+    let mut items = Vec::new();
+
+    while !rest.is_empty() {
+        // Quoted items may themselves contain commas.
+        let end = match rest.strip_prefix('"') {
+            Some(quoted) => quoted.find('"')? + 2,
+            None => rest.find(',').unwrap_or(rest.len()),
+        };
+        items.push(parse_value(rest[..end].trim())?);
+        rest = rest[end..].trim_start();
+        if let Some(after) = rest.strip_prefix(',') {
+            rest = after.trim_start();
+        } else if !rest.is_empty() {
+            return None;
+        }
+    }
+
+    Some(items)
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(retry_after) = self.retry_after {
+            writeln!(f, "retry_after = {retry_after}")?;
+        }
+        for part in [1, 2] {
+            if let Some(answer) = self.part(part) {
+                writeln!(f, "part{part} = \"{answer}\"")?;
+            }
+
+            let attempts = self.attempts(part);
+            if !attempts.rejected.is_empty() {
+                let rejected: Vec<_> = attempts
+                    .rejected
+                    .iter()
+                    .map(|a| format!("\"{a}\""))
+                    .collect();
+                writeln!(f, "part{part}_rejected = [{}]", rejected.join(", "))?;
+            }
+            if let Some(too_low) = attempts.too_low {
+                writeln!(f, "part{part}_too_low = {too_low}")?;
+            }
+            if let Some(too_high) = attempts.too_high {
+                writeln!(f, "part{part}_too_high = {too_high}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, io::Error),
+    Parse {
+        path: PathBuf,
+        line: usize,
+        msg: String,
+    },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnswersError::Io(path, e) => write!(f, "failed to access {}: {e}", path.display()),
+            AnswersError::Parse { path, line, msg } => {
+                write!(f, "{}:{line}: {msg}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AnswersError::Io(_, e) => Some(e),
+            AnswersError::Parse { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let answers = Answers {
+            part1: Some("3749".to_string()),
+            part2: Some("4,6,3".to_string()),
+            ..Answers::default()
+        };
+        assert_eq!(Answers::parse(&answers.to_string()), Ok(answers));
+    }
+
+    #[test]
+    fn round_trip_with_attempts() {
+        let mut answers = Answers {
+            part1: Some("3749".to_string()),
+            retry_after: Some(1_733_130_000),
+            ..Answers::default()
+        };
+        *answers.attempts_mut(2) = Attempts {
+            rejected: vec!["4,6,3".to_string(), "17".to_string()],
+            too_low: Some(17),
+            too_high: None,
+        };
+        assert_eq!(Answers::parse(&answers.to_string()), Ok(answers));
+    }
+
+    #[test]
+    fn parse_accepts_comments_and_bare_integers() {
+        let answers = Answers::parse("# Day 7\npart1 = 3749\n\npart2 = \"11387\"\n").unwrap();
+        assert_eq!(answers.part(1), Some("3749"));
+        assert_eq!(answers.part(2), Some("11387"));
+    }
+
+    #[test]
+    fn parse_reports_line_of_bad_entry() {
+        assert_eq!(
+            Answers::parse("part1 = 1\npart3 = 2\n"),
+            Err((2, "unknown key `part3`".to_string()))
+        );
+        assert_eq!(
+            Answers::parse("part1 = \"12\n"),
+            Err((1, "invalid value".to_string()))
+        );
+    }
+
+    #[test]
+    fn years_are_kept_apart() {
+        let dir = crate::test_support::scratch_dir("answers");
+        let answers = Answers {
+            part1: Some("11".to_string()),
+            ..Answers::default()
+        };
+        answers.save(&dir, 2024, 1).unwrap();
+
+        assert!(dir.join("2024").join("day01.toml").is_file());
+        assert_eq!(Answers::load(&dir, 2024, 1).unwrap(), answers);
+        assert_eq!(Answers::load(&dir, 2023, 1).unwrap(), Answers::default());
+    }
+}
@@ -0,0 +1,303 @@
+// Wall-clock benchmarks for the parse, part 1 and part 2 stages of a day, and
+// a flat JSON baseline of median timings to compare later runs against:
+//
+//     {
+//       "2024.day6.parse": 91236,
+//       "2024.day6.part1": 1200451
+//     }
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::runner::catch;
+use crate::Solver;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub fn name(self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarise a non-empty set of samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StageBench {
+    pub stage: Stage,
+    /// Timings, or why the stage could not be timed.
+    pub result: Result<Stats, String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayBench {
+    pub day: u8,
+    pub stages: Vec<StageBench>,
+}
+
+/// Time each stage of `solver` over `iterations` runs. Parts are timed
+/// against a single parsed input; `part` restricts which parts are run.
+pub fn bench_day(
+    solver: &dyn Solver,
+    input: &str,
+    iterations: usize,
+    part: Option<u8>,
+) -> DayBench {
+    let iterations = iterations.max(1);
+    let mut stages = Vec::with_capacity(3);
+
+    let parse = time(iterations, || solver.parse(input)).and_then(|(stats, parsed)| {
+        parsed
+            .map(|parsed| (stats, parsed))
+            .map_err(|e| e.to_string())
+    });
+    let parsed = match parse {
+        Ok((stats, parsed)) => {
+            stages.push(StageBench {
+                stage: Stage::Parse,
+                result: Ok(stats),
+            });
+            parsed
+        }
+        Err(msg) => {
+            stages.push(StageBench {
+                stage: Stage::Parse,
+                result: Err(msg),
+            });
+            return DayBench {
+                day: solver.day(),
+                stages,
+            };
+        }
+    };
+
+    for (p, stage) in [(1, Stage::Part1), (2, Stage::Part2)] {
+        if part.is_some_and(|only| only != p) {
+            continue;
+        }
+        let result = time(iterations, || match p {
+            1 => solver.part1(parsed.as_ref()),
+            _ => solver.part2(parsed.as_ref()),
+        });
+        stages.push(StageBench {
+            stage,
+            result: result.map(|(stats, _)| stats),
+        });
+    }
+
+    DayBench {
+        day: solver.day(),
+        stages,
+    }
+}
+
+/// Run `f` `iterations` times, returning the timings and the last result. A
+/// panic aborts the remaining iterations.
+fn time<T>(iterations: usize, mut f: impl FnMut() -> T) -> Result<(Stats, T), String> {
+    let mut samples = Vec::with_capacity(iterations);
+    let mut last = None;
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let value = catch(&mut f).map_err(|msg| format!("panicked: {msg}"))?;
+        samples.push(start.elapsed());
+        last = Some(value);
+    }
+
+    Ok((
+        Stats::from_samples(samples),
+        last.expect("at least one iteration"),
+    ))
+}
+
+/// Median timings keyed by `{year}.day{N}.{stage}`, in nanoseconds.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline(BTreeMap<String, u128>);
+
+impl Baseline {
+    pub fn key(year: u16, day: u8, stage: Stage) -> String {
+        format!("{year}.day{day}.{}", stage.name())
+    }
+
+    pub fn get(&self, year: u16, day: u8, stage: Stage) -> Option<Duration> {
+        let nanos = *self.0.get(&Self::key(year, day, stage))?;
+        Some(Duration::from_nanos(nanos.try_into().unwrap_or(u64::MAX)))
+    }
+
+    pub fn record(&mut self, year: u16, bench: &DayBench) {
+        for stage in &bench.stages {
+            if let Ok(stats) = &stage.result {
+                self.0.insert(
+                    Self::key(year, bench.day, stage.stage),
+                    stats.median.as_nanos(),
+                );
+            }
+        }
+    }
+
+    /// Load a baseline to compare `year`'s timings with. A baseline holding
+    /// timings of any other year, or keys from before they named the year,
+    /// is refused rather than compared day by day.
+    pub fn load(path: &Path, year: u16) -> Result<Self, BaselineError> {
+        let text = std::fs::read_to_string(path).map_err(|e| BaselineError::Io(path.into(), e))?;
+        let baseline = Self::parse(&text).ok_or_else(|| BaselineError::Format(path.into()))?;
+        baseline
+            .check_year(year)
+            .map_err(|key| BaselineError::OtherYear {
+                path: path.into(),
+                year,
+                key,
+            })?;
+        Ok(baseline)
+    }
+
+    /// The first key that is not a timing for `year`, if any.
+    fn check_year(&self, year: u16) -> Result<(), String> {
+        let prefix = format!("{year}.");
+        match self.0.keys().find(|key| !key.starts_with(&prefix)) {
+            Some(key) => Err(key.clone()),
+            None => Ok(()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        std::fs::write(path, self.to_string()).map_err(|e| BaselineError::Io(path.into(), e))
+    }
+
+    /// Parse a flat JSON object of string keys and integer values.
+    fn parse(text: &str) -> Option<Self> {
+        let body = text.trim().strip_prefix('{')?.strip_suffix('}')?.trim();
+        let mut entries = BTreeMap::new();
+        if body.is_empty() {
+            return Some(Baseline(entries));
+        }
+
+        for entry in body.split(',') {
+            let (key, value) = entry.split_once(':')?;
+            let key = key.trim().strip_prefix('"')?.strip_suffix('"')?;
+            entries.insert(key.to_string(), value.trim().parse().ok()?);
+        }
+
+        Some(Baseline(entries))
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{{")?;
+        for (i, (key, nanos)) in self.0.iter().enumerate() {
+            let comma = if i + 1 < self.0.len() { "," } else { "" };
+            writeln!(f, "  \"{key}\": {nanos}{comma}")?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[derive(Debug)]
+pub enum BaselineError {
+    Io(PathBuf, io::Error),
+    Format(PathBuf),
+    /// The baseline has a key that is not for the year being benchmarked.
+    OtherYear {
+        path: PathBuf,
+        year: u16,
+        key: String,
+    },
+}
+
+impl fmt::Display for BaselineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BaselineError::Io(path, e) => write!(f, "failed to access {}: {e}", path.display()),
+            BaselineError::Format(path) => {
+                write!(f, "{} is not a benchmark baseline", path.display())
+            }
+            BaselineError::OtherYear { path, year, key } => write!(
+                f,
+                "{} is not a baseline for {year}: it has timing `{key}`",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BaselineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BaselineError::Io(_, e) => Some(e),
+            BaselineError::Format(_) | BaselineError::OtherYear { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_from_samples() {
+        let ms = Duration::from_millis;
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(9), ms(3), ms(7)]);
+        assert_eq!(
+            stats,
+            Stats {
+                min: ms(1),
+                median: ms(5),
+                max: ms(9)
+            }
+        );
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let mut baseline = Baseline::default();
+        baseline
+            .0
+            .insert(Baseline::key(2024, 6, Stage::Part2), 1_200_451);
+        baseline
+            .0
+            .insert(Baseline::key(2024, 6, Stage::Parse), 91_236);
+        assert_eq!(
+            Baseline::parse(&baseline.to_string()),
+            Some(baseline.clone())
+        );
+        assert_eq!(
+            baseline.get(2024, 6, Stage::Parse),
+            Some(Duration::from_nanos(91_236))
+        );
+        assert_eq!(baseline.get(2023, 6, Stage::Parse), None);
+        assert_eq!(baseline.check_year(2024), Ok(()));
+        assert_eq!(baseline.check_year(2023), Err("2024.day6.parse".into()));
+        assert_eq!(Baseline::parse("{}"), Some(Baseline::default()));
+        assert_eq!(Baseline::parse("[1, 2]"), None);
+    }
+}
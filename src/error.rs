@@ -0,0 +1,166 @@
+// Errors for malformed puzzle input, pointing at the offending token:
+//
+//     day 5, line 3, column 4: expected integer, found `x7`
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Filled in by [`Solver::parse`](crate::Solver::parse); `0` until then.
+    pub day: u8,
+    /// 1-based line number in the input.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub expected: String,
+    /// The offending text; empty at the end of a line or of the input.
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>, found: &str) -> Self {
+        ParseError {
+            day: 0,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.to_string(),
+        }
+    }
+
+    /// The input ended at `line` when `expected` was still to come.
+    pub fn end_of_input(line: usize, expected: impl Into<String>) -> Self {
+        Self::new(line, 1, expected, "")
+    }
+
+    pub fn for_day(self, day: u8) -> Self {
+        ParseError { day, ..self }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.day != 0 {
+            write!(f, "day {}, ", self.day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        match self.found.as_str() {
+            "" => write!(f, "found end of line"),
+            found => write!(f, "found `{found}`"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// One line of input, remembering where it came from so that tokens sliced
+/// out of it can be reported with their position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// 1-based line number.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// An error at `token`, which should be a slice of this line; anything
+    /// else is reported at the end of the line.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset + token.len() <= self.text.len())
+            .unwrap_or(self.text.len());
+        let column = self.text[..offset].chars().count() + 1;
+        ParseError::new(self.number, column, expected, token.trim())
+    }
+
+    /// Parse `token` (trimmed) as a `T`, reporting `expected` on failure.
+    pub fn parse<T: FromStr>(&self, token: &'a str, expected: &str) -> Result<T, ParseError> {
+        let token = token.trim();
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+
+    /// Split `text`, a slice of this line, around the first `sep`.
+    pub fn split_once(&self, text: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(sep)
+            .ok_or_else(|| self.error(&text[text.len()..], format!("`{sep}`")))
+    }
+
+    /// Strip `prefix` from the start of the line.
+    pub fn strip_prefix(&self, prefix: &str) -> Result<&'a str, ParseError> {
+        self.text
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(self.text, format!("`{prefix}`")))
+    }
+}
+
+/// The lines of `input`, numbered from 1, without line terminators.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text: text.strip_suffix('\r').unwrap_or(text),
+    })
+}
+
+/// The blank-line-separated sections of `input`, each a run of numbered
+/// lines. Lines holding only whitespace count as blank, and runs of them
+/// never produce an empty section.
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections = vec![Vec::new()];
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            if !sections.last().is_some_and(Vec::is_empty) {
+                sections.push(Vec::new());
+            }
+        } else {
+            sections.last_mut().unwrap().push(line);
+        }
+    }
+    if sections.last().is_some_and(Vec::is_empty) {
+        sections.pop();
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_position_of_bad_token() {
+        let line = lines("1 2\n3 x4\n").nth(1).unwrap();
+        let err = line.parse::<i32>(&line.text[2..], "integer").unwrap_err();
+        assert_eq!(err, ParseError::new(2, 3, "integer", "x4"));
+        assert_eq!(
+            err.for_day(5).to_string(),
+            "day 5, line 2, column 3: expected integer, found `x4`"
+        );
+    }
+
+    #[test]
+    fn sections_split_on_blank_lines() {
+        let input = "\na\nb\r\n\r\n  \nc\n\n";
+        let sections: Vec<Vec<_>> = sections(input)
+            .iter()
+            .map(|section| section.iter().map(|l| (l.number, l.text)).collect())
+            .collect();
+        assert_eq!(sections, [vec![(2, "a"), (3, "b")], vec![(6, "c")]]);
+        assert!(super::sections("").is_empty());
+    }
+
+    #[test]
+    fn missing_separator_points_past_end_of_line() {
+        let line = lines("47 53\r\n").next().unwrap();
+        let err = line.split_once(line.text, "|").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 6: expected `|`, found end of line"
+        );
+    }
+}
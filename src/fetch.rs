@@ -0,0 +1,308 @@
+// Downloads puzzle inputs into the inputs directory. An input never changes,
+// so a day that is already on disk is not requested again.
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::http::{Http, Request, Response};
+
+/// Environment variable holding the `session` cookie of a logged-in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable naming a file that holds the session token instead.
+pub const SESSION_FILE_VAR: &str = "AOC_SESSION_FILE";
+
+/// Environment variable overriding the site the puzzles are fetched from.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent with every request, as the site asks automated tools to identify
+/// themselves.
+pub const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (personal puzzle runner; requests are cached)"
+);
+
+/// Where and as whom to talk to the puzzle site.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Site {
+    pub base_url: String,
+    pub session: Option<String>,
+}
+
+impl Site {
+    /// Read the base URL and session token from the environment, falling back
+    /// to the session file.
+    pub fn from_env() -> Result<Self, FetchError> {
+        let base_url = std::env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = match std::env::var(SESSION_VAR) {
+            Ok(token) => Some(token),
+            Err(_) => match session_file() {
+                Some(path) => match std::fs::read_to_string(&path) {
+                    Ok(token) => Some(token),
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+                    Err(e) => return Err(FetchError::Io(path, e)),
+                },
+                None => None,
+            },
+        };
+
+        Ok(Site {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session
+                .map(|token| token.trim().to_string())
+                .filter(|token| !token.is_empty()),
+        })
+    }
+
+    pub fn day_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}", self.base_url)
+    }
+
+    /// Send `request` with the session cookie and user agent attached.
+    pub fn send(&self, http: &dyn Http, request: Request) -> Result<Response, FetchError> {
+        let session = self.session.as_deref().ok_or(FetchError::NoSession)?;
+        let request = request
+            .header("Cookie", &format!("session={session}"))
+            .header("User-Agent", USER_AGENT);
+        http.send(&request).map_err(FetchError::Http)
+    }
+}
+
+/// The session file: `$AOC_SESSION_FILE`, else `aoc/session` in the user's
+/// config directory.
+pub fn session_file() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(SESSION_FILE_VAR) {
+        return Some(path.into());
+    }
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config.join("aoc").join("session"))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already on disk.
+    Cached,
+    Downloaded,
+}
+
+/// Make sure the input for `day` of `year` is at `path`, downloading it if
+/// needed.
+pub fn fetch_input(
+    http: &dyn Http,
+    site: &Site,
+    year: u16,
+    day: u8,
+    path: &Path,
+) -> Result<Fetched, FetchError> {
+    // An empty file is what a paste-in placeholder or a failed download
+    // leaves behind, so it does not count as having the input.
+    if std::fs::metadata(path).is_ok_and(|m| m.is_file() && m.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+
+    let request = Request::get(format!("{}/input", site.day_url(year, day)));
+    let response = site.send(http, request)?;
+    check_status(year, day, &response)?;
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| FetchError::Io(dir.into(), e))?;
+    }
+    write_atomically(path, &response.body).map_err(|e| FetchError::Io(path.into(), e))?;
+    Ok(Fetched::Downloaded)
+}
+
+/// Write `contents` to a temporary file next to `path` and rename it into
+/// place, so an interrupted write never leaves a truncated file at `path`.
+fn write_atomically(path: &Path, contents: &str) -> io::Result<()> {
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(format!(".{}.tmp", std::process::id()));
+    let temp = PathBuf::from(temp);
+
+    let written = std::fs::write(&temp, contents).and_then(|()| std::fs::rename(&temp, path));
+    if written.is_err() {
+        let _ = std::fs::remove_file(&temp);
+    }
+    written
+}
+
+/// Map the site's error statuses onto what they mean for the user.
+pub(crate) fn check_status(year: u16, day: u8, response: &Response) -> Result<(), FetchError> {
+    match response.status {
+        200..=299 => Ok(()),
+        400 | 401 | 403 => Err(FetchError::SessionExpired),
+        404 => Err(FetchError::NotUnlocked(year, day)),
+        429 => Err(FetchError::RateLimited),
+        status => {
+            let detail = response.body.lines().next().unwrap_or("").trim();
+            Err(FetchError::Status(status, detail.to_string()))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession,
+    SessionExpired,
+    /// The year and day asked for.
+    NotUnlocked(u16, u8),
+    RateLimited,
+    /// Any other unsuccessful status, with the first line of the body.
+    Status(u16, String),
+    Http(io::Error),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::NoSession => {
+                write!(f, "no session token: set {SESSION_VAR}")?;
+                match session_file() {
+                    Some(path) => write!(f, " or write it to {}", path.display()),
+                    None => write!(f, " or {SESSION_FILE_VAR}"),
+                }
+            }
+            FetchError::SessionExpired => write!(
+                f,
+                "the session token was rejected; it has probably expired, so log in again and update it"
+            ),
+            FetchError::NotUnlocked(year, day) => {
+                write!(f, "day {day} of {year} is not unlocked yet")
+            }
+            FetchError::RateLimited => {
+                write!(f, "rate limited by the server; wait a while before retrying")
+            }
+            FetchError::Status(status, detail) => write!(f, "unexpected HTTP {status}: {detail}"),
+            FetchError::Http(e) => write!(f, "request failed: {e}"),
+            FetchError::Io(path, e) => write!(f, "failed to access {}: {e}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FetchError::Http(e) | FetchError::Io(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::http::tests::serve_once;
+    use crate::http::SystemHttp;
+    use crate::test_support::scratch_dir;
+    use std::cell::RefCell;
+
+    /// Replies with a canned response and remembers what it was sent.
+    pub(crate) struct FakeHttp {
+        pub response: Response,
+        pub requests: RefCell<Vec<Request>>,
+    }
+
+    impl FakeHttp {
+        pub(crate) fn new(status: u16, body: &str) -> Self {
+            FakeHttp {
+                response: Response {
+                    status,
+                    body: body.to_string(),
+                },
+                requests: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl Http for FakeHttp {
+        fn send(&self, request: &Request) -> io::Result<Response> {
+            self.requests.borrow_mut().push(request.clone());
+            Ok(self.response.clone())
+        }
+    }
+
+    pub(crate) fn site(base_url: &str) -> Site {
+        Site {
+            base_url: base_url.to_string(),
+            session: Some("abc123".to_string()),
+        }
+    }
+
+    #[test]
+    fn downloads_once_then_uses_cache() {
+        let (url, server) = serve_once(200, "3   4\n4   3\n");
+        let path = scratch_dir("fetch").join("day1.txt");
+
+        let fetched = fetch_input(&SystemHttp, &site(&url), 2024, 1, &path).unwrap();
+        assert_eq!(fetched, Fetched::Downloaded);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.0\r\n"));
+        assert!(request.contains("\r\nCookie: session=abc123\r\n"));
+        assert!(request.contains(&format!("\r\nUser-Agent: {USER_AGENT}\r\n")));
+
+        // The server has gone away, so a second request would fail.
+        let fetched = fetch_input(&SystemHttp, &site(&url), 2024, 1, &path).unwrap();
+        assert_eq!(fetched, Fetched::Cached);
+    }
+
+    #[test]
+    fn refetches_an_empty_input() {
+        let (url, server) = serve_once(200, "3   4\n");
+        let path = scratch_dir("fetch-empty").join("day01.txt");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "").unwrap();
+
+        let fetched = fetch_input(&SystemHttp, &site(&url), 2024, 1, &path).unwrap();
+        assert_eq!(fetched, Fetched::Downloaded);
+        server.join().unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "3   4\n");
+        assert_eq!(
+            std::fs::read_dir(path.parent().unwrap()).unwrap().count(),
+            1
+        );
+    }
+
+    #[test]
+    fn maps_error_statuses() {
+        let path = scratch_dir("fetch-errors").join("day25.txt");
+        let fetch = |status| {
+            fetch_input(
+                &FakeHttp::new(status, "nope\n"),
+                &site("x"),
+                2023,
+                25,
+                &path,
+            )
+        };
+
+        assert!(matches!(fetch(400), Err(FetchError::SessionExpired)));
+        assert!(matches!(fetch(404), Err(FetchError::NotUnlocked(2023, 25))));
+        assert!(matches!(fetch(429), Err(FetchError::RateLimited)));
+        assert!(matches!(fetch(502), Err(FetchError::Status(502, ref s)) if s == "nope"));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn requires_a_session() {
+        let site = Site {
+            session: None,
+            ..site("x")
+        };
+        let http = FakeHttp::new(200, "");
+        let path = scratch_dir("fetch-session").join("day1.txt");
+        assert!(matches!(
+            fetch_input(&http, &site, 2024, 1, &path),
+            Err(FetchError::NoSession)
+        ));
+        assert!(http.requests.borrow().is_empty());
+    }
+}
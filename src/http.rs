@@ -0,0 +1,277 @@
+// A minimal HTTP client behind a trait, so that commands talking to the
+// puzzle site can be tested against a stand-in. Plain `http://` URLs are
+// spoken directly over TCP; std has no TLS, so `https://` goes through the
+// system `curl`.
+
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// How long to wait on a stalled connection before giving up.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: &'static str,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    /// A form-encoded body, for `POST`.
+    pub body: Option<String>,
+}
+
+impl Request {
+    pub fn get(url: impl Into<String>) -> Self {
+        Request {
+            method: "GET",
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    pub fn post(url: impl Into<String>, body: impl Into<String>) -> Self {
+        Request {
+            method: "POST",
+            body: Some(body.into()),
+            ..Self::get(url)
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+pub trait Http {
+    fn send(&self, request: &Request) -> io::Result<Response>;
+}
+
+/// The real network: TCP for `http://`, `curl` for `https://`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemHttp;
+
+impl Http for SystemHttp {
+    fn send(&self, request: &Request) -> io::Result<Response> {
+        if let Some(rest) = request.url.strip_prefix("http://") {
+            send_plain(rest, request)
+        } else if request.url.starts_with("https://") {
+            send_curl(request)
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unsupported URL `{}`", request.url),
+            ))
+        }
+    }
+}
+
+/// Send over a plain TCP connection. HTTP/1.0 keeps the response unchunked
+/// and closes the connection when it is done.
+fn send_plain(url: &str, request: &Request) -> io::Result<Response> {
+    let (host, path) = match url.find('/') {
+        Some(i) => url.split_at(i),
+        None => (url, "/"),
+    };
+    let addr = if host.contains(':') {
+        host.to_string()
+    } else {
+        format!("{host}:80")
+    };
+
+    let mut stream = TcpStream::connect(addr)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    let mut head = format!("{} {path} HTTP/1.0\r\nHost: {host}\r\n", request.method);
+    for (name, value) in &request.headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    let body = request.body.as_deref().unwrap_or("");
+    if request.body.is_some() {
+        head.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+        head.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    head.push_str("\r\n");
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(body.as_bytes())?;
+
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw)?;
+    parse_response(&String::from_utf8_lossy(&raw))
+}
+
+fn parse_response(raw: &str) -> io::Result<Response> {
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "malformed HTTP response");
+    let (head, body) = raw.split_once("\r\n\r\n").ok_or_else(invalid)?;
+    let status = head
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(invalid)?;
+
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+/// Send through `curl`, which prints the status code on a line after the body.
+/// Headers and body go to curl as a config file on stdin rather than as
+/// arguments, so the session cookie never shows up in the process list.
+fn send_curl(request: &Request) -> io::Result<Response> {
+    let mut command = Command::new("curl");
+    command
+        .args(["--silent", "--show-error", "--max-time", "30"])
+        .args(["--request", request.method])
+        .args(["--write-out", "\n%{http_code}"])
+        .args(["--config", "-"])
+        .arg(&request.url);
+
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| io::Error::new(e.kind(), format!("failed to run curl: {e}")))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(curl_config(request).as_bytes())?;
+    }
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(io::Error::other(format!("curl: {}", stderr.trim())));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = stdout.rsplit_once('\n').unwrap_or(("", &stdout));
+    let status = status
+        .trim()
+        .parse()
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "curl gave no status code"))?;
+
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+/// The headers and body of `request` in curl's config file syntax.
+fn curl_config(request: &Request) -> String {
+    let mut config = String::new();
+    for (name, value) in &request.headers {
+        config.push_str(&format!(
+            "header = {}\n",
+            curl_quote(&format!("{name}: {value}"))
+        ));
+    }
+    if let Some(body) = &request.body {
+        config.push_str(&format!("data-binary = {}\n", curl_quote(body)));
+    }
+    config
+}
+
+/// A double-quoted curl config value.
+fn curl_quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serve one canned response on a local port, returning the base URL and
+    /// a handle yielding the raw request that was received.
+    pub(crate) fn serve_once(status: u16, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {status} X\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        );
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 4096];
+            // Read the head, then as much body as Content-Length promises.
+            loop {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&request);
+                if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                    let length = head
+                        .lines()
+                        .find_map(|l| l.strip_prefix("Content-Length: "))
+                        .map_or(0, |n| n.parse().unwrap());
+                    if body.len() >= length {
+                        break;
+                    }
+                }
+                if n == 0 {
+                    break;
+                }
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn plain_http_round_trip() {
+        let (url, server) = serve_once(200, "1 2\n3 4\n");
+        let request = Request::post(format!("{url}/2024/day/1/answer"), "level=1&answer=11")
+            .header("Cookie", "session=abc");
+        let response = SystemHttp.send(&request).unwrap();
+        assert_eq!(
+            response,
+            Response {
+                status: 200,
+                body: "1 2\n3 4\n".to_string()
+            }
+        );
+
+        let received = server.join().unwrap();
+        assert!(received.starts_with("POST /2024/day/1/answer HTTP/1.0\r\n"));
+        assert!(received.contains("\r\nCookie: session=abc\r\n"));
+        assert!(received.ends_with("\r\n\r\nlevel=1&answer=11"));
+    }
+
+    #[test]
+    fn curl_gets_secrets_on_stdin() {
+        let request = Request::post("https://example.com/answer", "level=1&answer=\"x\"")
+            .header("Cookie", "session=abc\\123");
+        assert_eq!(
+            curl_config(&request),
+            "header = \"Cookie: session=abc\\\\123\"\ndata-binary = \"level=1&answer=\\\"x\\\"\"\n"
+        );
+    }
+}
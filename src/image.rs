@@ -0,0 +1,476 @@
+// Pictures of puzzle states for write-ups. A grid is turned into one colour
+// per cell, optionally with paths drawn over it, and saved as PPM, PNG or
+// SVG. The PNG encoder (zlib with fixed-Huffman deflate) is written here so
+// nothing outside std is needed.
+
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+
+use crate::utils::geom::Point;
+use crate::utils::grid::Grid;
+
+/// Pixels per cell side in PPM and PNG output.
+pub const CELL_PIXELS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    /// The `n`th of a sequence of colours spread around the colour wheel, so
+    /// neighbouring `n` look different.
+    pub fn distinct(n: usize) -> Rgb {
+        // Golden-angle steps never line up with earlier hues.
+        let hue = (n as f64 * 137.508) % 360.0;
+        let (value, saturation) = (0.9, 0.65);
+        let chroma = value * saturation;
+        let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+        let (r, g, b) = match hue as u32 / 60 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let channel = |c: f64| ((c + value - chroma) * 255.0).round() as u8;
+        Rgb(channel(r), channel(g), channel(b))
+    }
+
+    /// `#rrggbb`.
+    pub fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// A line through the centres of a sequence of cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overlay {
+    pub points: Vec<Point>,
+    pub color: Rgb,
+}
+
+/// One colour per grid cell, plus any overlaid paths.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    width: usize,
+    height: usize,
+    cells: Vec<Rgb>,
+    overlays: Vec<Overlay>,
+}
+
+impl Picture {
+    /// Colour each cell of `grid` with `palette`.
+    pub fn from_grid<T>(
+        grid: &Grid<T>,
+        mut palette: impl FnMut((usize, usize), &T) -> Rgb,
+    ) -> Self {
+        Picture {
+            width: grid.width(),
+            height: grid.height(),
+            cells: grid.iter().map(|(pos, cell)| palette(pos, cell)).collect(),
+            overlays: Vec::new(),
+        }
+    }
+
+    /// Draw a path over the cells.
+    pub fn with_path(mut self, points: Vec<Point>, color: Rgb) -> Self {
+        self.overlays.push(Overlay { points, color });
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Write to `path` in the format its extension names: `png`, `ppm` or
+    /// `svg`.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let bytes = match path.extension().and_then(|e| e.to_str()) {
+            Some("png") => self.to_png(CELL_PIXELS),
+            Some("ppm") => self.to_ppm(CELL_PIXELS),
+            Some("svg") => self.to_svg().into_bytes(),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{}: expected a .png, .ppm or .svg file", path.display()),
+                ))
+            }
+        };
+        std::fs::write(path, bytes)
+    }
+
+    /// Binary PPM (`P6`) with `scale` pixels per cell side.
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let (width, height, pixels) = self.raster(scale);
+        let mut ppm = format!("P6\n{width} {height}\n255\n").into_bytes();
+        ppm.extend(pixels.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]));
+        ppm
+    }
+
+    /// 8-bit RGB PNG with `scale` pixels per cell side.
+    pub fn to_png(&self, scale: usize) -> Vec<u8> {
+        let (width, height, pixels) = self.raster(scale);
+        let mut scanlines = Vec::with_capacity(height * (1 + 3 * width));
+        for row in pixels.chunks(width.max(1)).take(height) {
+            // Filter type 0: the row as is.
+            scanlines.push(0);
+            scanlines.extend(row.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]));
+        }
+
+        let mut header = Vec::with_capacity(13);
+        header.extend((width as u32).to_be_bytes());
+        header.extend((height as u32).to_be_bytes());
+        // Bit depth 8, colour type 2 (RGB), then the only defined
+        // compression and filter methods, and no interlacing.
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut png, b"IHDR", &header);
+        png_chunk(&mut png, b"IDAT", &zlib(&scanlines));
+        png_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    /// SVG with one unit per cell, and overlays as lines through cell
+    /// centres.
+    pub fn to_svg(&self) -> String {
+        let (width, height) = (self.width, self.height);
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+             viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">\n",
+            width * 10,
+            height * 10,
+        );
+        for (row, cells) in self.cells.chunks(width.max(1)).enumerate() {
+            // One rectangle per run of same-coloured cells.
+            let mut col = 0;
+            for run in cells.chunk_by(|a, b| a == b) {
+                writeln!(
+                    svg,
+                    "<rect x=\"{col}\" y=\"{row}\" width=\"{}\" height=\"1\" fill=\"{}\"/>",
+                    run.len(),
+                    run[0].hex()
+                )
+                .unwrap();
+                col += run.len();
+            }
+        }
+        for overlay in &self.overlays {
+            let points: Vec<_> = overlay
+                .points
+                .iter()
+                .map(|p| format!("{}.5,{}.5", p.col, p.row))
+                .collect();
+            writeln!(
+                svg,
+                "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"0.3\" \
+                 stroke-linecap=\"round\" stroke-linejoin=\"round\" shape-rendering=\"auto\"/>",
+                points.join(" "),
+                overlay.color.hex()
+            )
+            .unwrap();
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Pixels in row-major order with overlays drawn in, and the image size.
+    fn raster(&self, scale: usize) -> (usize, usize, Vec<Rgb>) {
+        let (width, height) = (self.width * scale, self.height * scale);
+        let mut pixels = vec![Rgb::BLACK; width * height];
+        for (y, row) in pixels.chunks_mut(width.max(1)).enumerate() {
+            for (x, pixel) in row.iter_mut().enumerate() {
+                *pixel = self.cells[(y / scale) * self.width + x / scale];
+            }
+        }
+
+        // Overlays are drawn a third of a cell thick between cell centres.
+        let thickness = (scale / 3).max(1) as i64;
+        let centre = |p: Point| {
+            let half = scale as i64 / 2;
+            (
+                p.col as i64 * scale as i64 + half,
+                p.row as i64 * scale as i64 + half,
+            )
+        };
+        for overlay in &self.overlays {
+            let mut plot = |x: i64, y: i64| {
+                for dy in 0..thickness {
+                    for dx in 0..thickness {
+                        let (x, y) = (x + dx - thickness / 2, y + dy - thickness / 2);
+                        if (0..width as i64).contains(&x) && (0..height as i64).contains(&y) {
+                            pixels[y as usize * width + x as usize] = overlay.color;
+                        }
+                    }
+                }
+            };
+            let centres: Vec<_> = overlay.points.iter().map(|&p| centre(p)).collect();
+            if let [only] = centres[..] {
+                plot(only.0, only.1);
+            }
+            for pair in centres.windows(2) {
+                let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+                let steps = (x1 - x0).abs().max((y1 - y0).abs()).max(1);
+                for i in 0..=steps {
+                    plot(x0 + (x1 - x0) * i / steps, y0 + (y1 - y0) * i / steps);
+                }
+            }
+        }
+
+        (width, height, pixels)
+    }
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+/// The CRC-32 used by PNG (and zip, gzip, ...).
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+/// A zlib stream holding `data` as one fixed-Huffman deflate block.
+fn zlib(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32K window, no preset dictionary, default level.
+    let mut bits = BitWriter {
+        out: vec![0x78, 0x9c],
+        acc: 0,
+        len: 0,
+    };
+    // The last block, compressed with the fixed codes.
+    bits.write(0b1, 1);
+    bits.write(0b01, 2);
+    deflate(data, &mut bits);
+    bits.write_symbol(256);
+    let mut out = bits.finish();
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+const WINDOW: usize = 32 * 1024;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+/// Candidates tried per position.
+const MAX_CHAIN: usize = 32;
+
+const LENGTH_BASE: [usize; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u32; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [usize; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u32; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+/// LZ77 with greedy matching: at each position take the longest match
+/// among the last few positions that start with the same three bytes.
+fn deflate(data: &[u8], bits: &mut BitWriter) {
+    const NONE: u32 = u32::MAX;
+    // The latest position with each hash, and for each position the one
+    // before it with the same hash.
+    let mut head = vec![NONE; 1 << 15];
+    let mut chain = vec![NONE; data.len()];
+    let mut insert = |chain: &mut [u32], i: usize| {
+        if i + MIN_MATCH <= data.len() {
+            let key = u32::from_be_bytes([0, data[i], data[i + 1], data[i + 2]]);
+            let hash = key.wrapping_mul(2_654_435_761) as usize >> 17;
+            chain[i] = std::mem::replace(&mut head[hash], i as u32);
+        }
+    };
+    let match_length = |from: usize, i: usize| {
+        data[from..]
+            .iter()
+            .zip(&data[i..])
+            .take(MAX_MATCH)
+            .take_while(|(a, b)| a == b)
+            .count()
+    };
+
+    let mut i = 0;
+    while i < data.len() {
+        insert(&mut chain, i);
+        let (mut length, mut distance) = (0, 0);
+        let mut candidate = chain[i];
+        for _ in 0..MAX_CHAIN {
+            if candidate == NONE || i - candidate as usize > WINDOW {
+                break;
+            }
+            let found = match_length(candidate as usize, i);
+            if found > length {
+                (length, distance) = (found, i - candidate as usize);
+            }
+            candidate = chain[candidate as usize];
+        }
+
+        if length < MIN_MATCH {
+            bits.write_symbol(data[i] as u16);
+            i += 1;
+            continue;
+        }
+
+        let code = LENGTH_BASE.partition_point(|&base| base <= length) - 1;
+        bits.write_symbol(257 + code as u16);
+        bits.write((length - LENGTH_BASE[code]) as u32, LENGTH_EXTRA[code]);
+
+        let code = DISTANCE_BASE.partition_point(|&base| base <= distance) - 1;
+        bits.write_code(code as u32, 5);
+        bits.write(
+            (distance - DISTANCE_BASE[code]) as u32,
+            DISTANCE_EXTRA[code],
+        );
+
+        for j in i + 1..i + length {
+            insert(&mut chain, j);
+        }
+        i += length;
+    }
+}
+
+/// Packs bits least significant first, as deflate wants.
+struct BitWriter {
+    out: Vec<u8>,
+    acc: u64,
+    len: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, len: u32) {
+        self.acc |= (value as u64) << self.len;
+        self.len += len;
+        while self.len >= 8 {
+            self.out.push(self.acc as u8);
+            self.acc >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    /// Huffman codes go most significant bit first.
+    fn write_code(&mut self, code: u32, len: u32) {
+        self.write(code.reverse_bits() >> (32 - len), len);
+    }
+
+    /// A literal/length symbol in the fixed Huffman code.
+    fn write_symbol(&mut self, symbol: u16) {
+        let symbol = symbol as u32;
+        match symbol {
+            0..=143 => self.write_code(0x30 + symbol, 8),
+            144..=255 => self.write_code(0x190 + symbol - 144, 9),
+            256..=279 => self.write_code(symbol - 256, 7),
+            _ => self.write_code(0xc0 + symbol - 280, 8),
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.out.push(self.acc as u8);
+        }
+        self.out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Picture {
+        let grid = Grid::parse("#.\n.#\n", |c| c == '#').unwrap();
+        Picture::from_grid(&grid, |_, &wall| if wall { Rgb::BLACK } else { Rgb::WHITE })
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn writes_ppm() {
+        let ppm = checkerboard().to_ppm(1);
+        let mut expected = b"P6\n2 2\n255\n".to_vec();
+        expected.extend([0, 0, 0, 255, 255, 255, 255, 255, 255, 0, 0, 0]);
+        assert_eq!(ppm, expected);
+        assert_eq!(
+            checkerboard().to_ppm(3).len(),
+            b"P6\n6 6\n255\n".len() + 6 * 6 * 3
+        );
+    }
+
+    #[test]
+    fn writes_png_chunks() {
+        let png = checkerboard().to_png(2);
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..29], [0, 0, 0, 4, 0, 0, 0, 4, 8, 2, 0, 0, 0]);
+        assert_eq!(png[29..33], crc32(&png[12..29]).to_be_bytes());
+        assert_eq!(&png[png.len() - 12..], b"\0\0\0\0IEND\xae\x42\x60\x82");
+    }
+
+    #[test]
+    fn compresses_repeats() {
+        // Repeated rows become back-references.
+        let data = [[0u8; 13], [1; 13]].concat().repeat(20);
+        let compressed = zlib(&data);
+        assert!(compressed.len() < data.len() / 4, "{}", compressed.len());
+        assert_eq!(compressed[..2], [0x78, 0x9c]);
+        assert_eq!(
+            compressed[compressed.len() - 4..],
+            adler32(&data).to_be_bytes()
+        );
+    }
+
+    #[test]
+    fn writes_svg_runs_and_paths() {
+        let grid = Grid::parse("..#\n", |c| c).unwrap();
+        let svg = Picture::from_grid(&grid, |_, &c| match c {
+            '#' => Rgb::BLACK,
+            _ => Rgb::WHITE,
+        })
+        .with_path(vec![Point::new(0, 0), Point::new(0, 1)], Rgb(255, 0, 0))
+        .to_svg();
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"2\" height=\"1\" fill=\"#ffffff\"/>"));
+        assert!(svg.contains("<rect x=\"2\" y=\"0\" width=\"1\" height=\"1\" fill=\"#000000\"/>"));
+        assert!(svg.contains("points=\"0.5,0.5 1.5,0.5\""));
+        assert!(svg.contains("stroke=\"#ff0000\""));
+    }
+}
@@ -0,0 +1,204 @@
+// Puzzle input loading: a directory of `<year>/dayNN.txt` files, one explicit
+// file, or standard input.
+
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming the directory that holds `<year>/dayNN.txt`
+/// files.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Directory used when neither `--input` nor `AOC_INPUT_DIR` is given.
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// Read `<year>/dayNN.txt` from under this directory.
+    Dir(PathBuf),
+    /// Read this exact file, whatever the day.
+    File(PathBuf),
+    /// Read everything from standard input.
+    Stdin,
+}
+
+impl InputSource {
+    /// Pick the source from a `--input` argument (`-` meaning stdin), falling
+    /// back to `AOC_INPUT_DIR` and then to `inputs/`.
+    pub fn resolve(arg: Option<&str>) -> Self {
+        match arg {
+            Some("-") => InputSource::Stdin,
+            Some(path) if Path::new(path).is_dir() => InputSource::Dir(path.into()),
+            Some(path) => InputSource::File(path.into()),
+            None => match std::env::var_os(INPUT_DIR_VAR) {
+                Some(dir) => InputSource::Dir(dir.into()),
+                None => InputSource::Dir(DEFAULT_INPUT_DIR.into()),
+            },
+        }
+    }
+
+    /// Whether this source can serve more than one day.
+    pub fn is_per_day(&self) -> bool {
+        matches!(self, InputSource::Dir(_))
+    }
+
+    /// Path the input for `day` of `year` would be read from, if it comes
+    /// from disk.
+    pub fn path(&self, year: u16, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::Dir(dir) => {
+                Some(dir.join(year.to_string()).join(format!("day{day:02}.txt")))
+            }
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    /// Read the input for `day` of `year`, [normalised](normalize).
+    pub fn read(&self, year: u16, day: u8) -> Result<String, InputError> {
+        self.read_raw(year, day).map(|raw| normalize(&raw))
+    }
+
+    fn read_raw(&self, year: u16, day: u8) -> Result<String, InputError> {
+        match self.path(year, day) {
+            Some(path) => std::fs::read_to_string(&path).map_err(|e| match e.kind() {
+                io::ErrorKind::NotFound => InputError::NotFound(path),
+                _ => InputError::Io(Some(path), e),
+            }),
+            None => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| InputError::Io(None, e))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// The only year whose files were kept in the flat, unpadded layout
+/// (`dayN.txt` straight in the directory) before years had folders.
+pub const LEGACY_YEAR: u16 = 2024;
+
+/// Files left in the flat layout, `dir/dayN.<extension>`, with their days.
+pub fn legacy_files(dir: &Path, extension: &str) -> Vec<(u8, PathBuf)> {
+    (1..=25)
+        .map(|day| (day, dir.join(format!("day{day}.{extension}"))))
+        .filter(|(_, path)| path.is_file())
+        .collect()
+}
+
+/// Move files left in the flat layout, `dir/dayN.<extension>`, to where
+/// `new_path` says day `N` of [`LEGACY_YEAR`] now lives. A file whose new
+/// place is already taken stays put. Returns the moves made.
+pub fn migrate_legacy(
+    dir: &Path,
+    extension: &str,
+    new_path: impl Fn(u8) -> PathBuf,
+) -> io::Result<Vec<(PathBuf, PathBuf)>> {
+    let mut moved = Vec::new();
+    for (day, old) in legacy_files(dir, extension) {
+        let new = new_path(day);
+        if new.exists() {
+            continue;
+        }
+        if let Some(parent) = new.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::rename(&old, &new)?;
+        moved.push((old, new));
+    }
+    Ok(moved)
+}
+
+/// Make an input look the same whichever checkout or editor it came from:
+/// drop a byte-order mark, turn CRLF and lone CR line endings into LF, strip
+/// trailing whitespace from every line and blank lines from the end, and
+/// finish with a single newline.
+pub fn normalize(raw: &str) -> String {
+    let raw = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+    let raw = raw.replace("\r\n", "\n").replace('\r', "\n");
+    let mut out = String::with_capacity(raw.len() + 1);
+    for line in raw.split('\n') {
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+
+    let end = out.trim_end().len();
+    out.truncate(end);
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    /// Any other I/O failure; the path is `None` for stdin.
+    Io(Option<PathBuf>, io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(f, "input file not found: {}", path.display()),
+            InputError::Io(Some(path), e) => write!(f, "failed to read {}: {e}", path.display()),
+            InputError::Io(None, e) => write!(f, "failed to read stdin: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::NotFound(_) => None,
+            InputError::Io(_, e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_line_endings_and_whitespace() {
+        let raw = "\u{feff}1  2 \r\n3\t4\r\n\r\n5 6\r7\n  \n\n";
+        assert_eq!(normalize(raw), "1  2\n3\t4\n\n5 6\n7\n");
+        assert_eq!(normalize("x"), "x\n");
+        assert_eq!(normalize(" \r\n"), "");
+    }
+
+    #[test]
+    fn directories_hold_a_folder_per_year() {
+        let dir = InputSource::Dir("inputs".into());
+        assert_eq!(
+            dir.path(2024, 7),
+            Some(Path::new("inputs").join("2024").join("day07.txt"))
+        );
+        let file = InputSource::File("mine.txt".into());
+        assert_eq!(file.path(2015, 25), Some("mine.txt".into()));
+    }
+
+    #[test]
+    fn moves_flat_inputs_into_their_year() {
+        let dir = crate::test_support::scratch_dir("input-legacy");
+        let source = InputSource::Dir(dir.clone());
+        std::fs::create_dir_all(dir.join("2024")).unwrap();
+        std::fs::write(dir.join("day1.txt"), "3 4\n").unwrap();
+        std::fs::write(dir.join("day2.txt"), "old\n").unwrap();
+        std::fs::write(dir.join("2024").join("day02.txt"), "new\n").unwrap();
+
+        let path = |day| source.path(LEGACY_YEAR, day).unwrap();
+        assert_eq!(
+            legacy_files(&dir, "txt"),
+            [(1, dir.join("day1.txt")), (2, dir.join("day2.txt"))]
+        );
+        let moved = migrate_legacy(&dir, "txt", path).unwrap();
+        assert_eq!(moved, [(dir.join("day1.txt"), path(1))]);
+        assert_eq!(source.read(2024, 1).unwrap(), "3 4\n");
+        assert_eq!(source.read(2024, 2).unwrap(), "new\n");
+        assert!(dir.join("day2.txt").is_file());
+    }
+}
@@ -1 +1,87 @@
+pub mod alloc_stats;
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod fetch;
+pub mod http;
+pub mod image;
+pub mod input;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
 pub mod utils;
+pub mod visualize;
+pub mod watch;
+pub mod y2024;
+pub mod years;
+
+#[cfg(test)]
+mod test_support;
+
+use std::any::Any;
+use std::fmt::Display;
+
+use crate::error::ParseError;
+
+/// A day's puzzle: parse the input once, then answer both parts from it.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Object-safe view of a [`Solution`], so every day can live in one registry.
+pub trait Solver: Sync {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    /// Parse `input`, tagging any error with this solver's day.
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn part1(&self, parsed: &dyn Any) -> String;
+    fn part2(&self, parsed: &dyn Any) -> String;
+}
+
+impl<S> Solver for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        match <S as Solution>::parse(input) {
+            Ok(parsed) => Ok(Box::new(parsed)),
+            Err(e) => Err(e.for_day(S::DAY)),
+        }
+    }
+
+    fn part1(&self, parsed: &dyn Any) -> String {
+        <S as Solution>::part1(downcast::<S>(parsed)).to_string()
+    }
+
+    fn part2(&self, parsed: &dyn Any) -> String {
+        <S as Solution>::part2(downcast::<S>(parsed)).to_string()
+    }
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    parsed
+        .downcast_ref()
+        .unwrap_or_else(|| panic!("input was not parsed by day {}", S::DAY))
+}
@@ -1,3 +1,891 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc::alloc_stats;
+use aoc::answers::Answers;
+use aoc::bench::{self, Baseline, DayBench};
+use aoc::error::ParseError;
+use aoc::fetch::{self, Fetched, Site};
+use aoc::http::SystemHttp;
+use aoc::input::{self, InputSource};
+use aoc::report::{self, Format, Record};
+use aoc::runner::{self, DayRun, Outcome};
+use aoc::scaffold;
+use aoc::submit::{self, Verdict};
+use aoc::visualize::{self, FrameDump, Player};
+use aoc::watch::{self, Watcher};
+use aoc::years::{self, Year};
+use aoc::Solver;
+
+const USAGE: &str = "\
+usage: aoc run <day|all> [--part 1|2] [--input <path|->] [--jobs N]
+                [--format text|json|csv] [--dump-image <file.png|.ppm|.svg>]
+       aoc run <day> --visualize [--fps N] [--frames <dir>]
+       aoc verify [day|all] [--input <dir>]
+       aoc record <day> [--part 1|2] [--input <path|->]
+       aoc bench <day|all> [--part 1|2] [--input <path|->] [--iterations N]
+                 [--baseline <file>] [--save-baseline <file>]
+       aoc fetch <day> [--input <dir>]
+       aoc submit <day> <part> [--input <path|->]
+       aoc new <day> [--title <title>] [--input <dir>]
+       aoc gen <day> [--seed S] [--size N]
+       aoc watch <day> [--input <path>]
+       aoc migrate [--input <dir>]
+
+Every command also takes --year <year>, defaulting to the latest year solved.";
+
+/// Default number of timed runs per stage for `aoc bench`.
+const BENCH_ITERATIONS: usize = 10;
+
+/// How often `aoc watch` checks for changed files.
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Median slowdown against the baseline that `aoc bench` flags as a regression.
+const REGRESSION_THRESHOLD: f64 = 0.10;
+
+type CliResult<T = ()> = Result<T, Box<dyn std::error::Error>>;
+
+/// A malformed command line; reported together with the usage text.
+#[derive(Debug)]
+struct Usage(String);
+
+impl std::fmt::Display for Usage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Usage {}
+
+fn usage<T>(msg: impl Into<String>) -> CliResult<T> {
+    Err(Box::new(Usage(msg.into())))
+}
+
+#[derive(Default)]
+struct Args<'a> {
+    positional: Vec<&'a str>,
+    year: Option<u16>,
+    part: Option<u8>,
+    input: Option<&'a str>,
+    iterations: Option<usize>,
+    baseline: Option<&'a str>,
+    save_baseline: Option<&'a str>,
+    title: Option<&'a str>,
+    jobs: Option<usize>,
+    format: Format,
+    seed: Option<u64>,
+    size: Option<usize>,
+    visualize: bool,
+    fps: Option<u32>,
+    frames: Option<&'a str>,
+    dump_image: Option<&'a str>,
+}
+
+impl Args<'_> {
+    fn year(&self) -> u16 {
+        self.year.unwrap_or(years::DEFAULT_YEAR)
+    }
+}
+
 fn main() {
-    println!("Hello, world!");
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if let Err(e) = dispatch(&args) {
+        eprintln!("error: {e}");
+        if e.is::<Usage>() {
+            eprintln!("{USAGE}");
+        }
+        std::process::exit(1);
+    }
+}
+
+fn dispatch(args: &[String]) -> CliResult {
+    let Some((command, rest)) = args.split_first() else {
+        return usage("missing command");
+    };
+    let args = parse_args(rest)?;
+
+    match command.as_str() {
+        "run" => run(&args),
+        "verify" => verify(&args),
+        "record" => record(&args),
+        "bench" => bench(&args),
+        "fetch" => fetch(&args),
+        "submit" => submit(&args),
+        "new" => new(&args),
+        "gen" => generate(&args),
+        "watch" => watch(&args),
+        "migrate" => migrate(&args),
+        _ => usage(format!("unknown command `{command}`")),
+    }
+}
+
+fn parse_args(args: &[String]) -> CliResult<Args<'_>> {
+    let mut parsed = Args::default();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let mut value = || match args.next() {
+            Some(value) => Ok(value.as_str()),
+            None => usage(format!("missing value for {arg}")),
+        };
+
+        match arg.as_str() {
+            "--part" => {
+                parsed.part = match value()? {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    part => return usage(format!("invalid part `{part}`")),
+                };
+            }
+            "--year" => {
+                let year = value()?;
+                match year.parse() {
+                    Ok(year) if year >= years::FIRST_YEAR => parsed.year = Some(year),
+                    _ => return usage(format!("invalid year `{year}`")),
+                }
+            }
+            "--input" => parsed.input = Some(value()?),
+            "--iterations" => {
+                let n = value()?;
+                match n.parse() {
+                    Ok(n) if n > 0 => parsed.iterations = Some(n),
+                    _ => return usage(format!("invalid iteration count `{n}`")),
+                }
+            }
+            "--baseline" => parsed.baseline = Some(value()?),
+            "--save-baseline" => parsed.save_baseline = Some(value()?),
+            "--title" => parsed.title = Some(value()?),
+            "--format" => {
+                parsed.format = match value()?.parse() {
+                    Ok(format) => format,
+                    Err(msg) => return usage(msg),
+                };
+            }
+            "--seed" => {
+                let seed = value()?;
+                match seed.parse() {
+                    Ok(seed) => parsed.seed = Some(seed),
+                    _ => return usage(format!("invalid seed `{seed}`")),
+                }
+            }
+            "--size" => {
+                let n = value()?;
+                match n.parse() {
+                    Ok(n) if n > 0 => parsed.size = Some(n),
+                    _ => return usage(format!("invalid size `{n}`")),
+                }
+            }
+            "--visualize" => parsed.visualize = true,
+            "--fps" => {
+                let n = value()?;
+                match n.parse() {
+                    Ok(n) if n > 0 => parsed.fps = Some(n),
+                    _ => return usage(format!("invalid frame rate `{n}`")),
+                }
+            }
+            "--frames" => parsed.frames = Some(value()?),
+            "--dump-image" => parsed.dump_image = Some(value()?),
+            "--jobs" => {
+                let n = value()?;
+                match n.parse() {
+                    Ok(n) if n > 0 => parsed.jobs = Some(n),
+                    _ => return usage(format!("invalid job count `{n}`")),
+                }
+            }
+            flag if flag.starts_with("--") => return usage(format!("unknown flag `{flag}`")),
+            _ => parsed.positional.push(arg),
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// The solutions for the `--year` asked for.
+fn solutions(args: &Args) -> CliResult<&'static Year> {
+    let year = args.year();
+    Ok(years::get(year).ok_or(format!("there are no solutions for {year}"))?)
+}
+
+/// Resolve the `<day|all>` argument, defaulting to `all` when allowed.
+fn select_days(args: &Args, default_all: bool) -> CliResult<Vec<&'static dyn Solver>> {
+    let target = match args.positional.as_slice() {
+        [] if default_all => "all",
+        [] => return usage("missing day"),
+        [target] => target,
+        [_, extra, ..] => return usage(format!("unexpected argument `{extra}`")),
+    };
+
+    match target {
+        "all" => Ok(solutions(args)?.days.to_vec()),
+        day => Ok(vec![solver(args, day)?]),
+    }
+}
+
+fn solver(args: &Args, day: &str) -> CliResult<&'static dyn Solver> {
+    let Ok(day) = day.parse::<u8>() else {
+        return usage(format!("invalid day `{day}`"));
+    };
+    let year = solutions(args)?;
+    Ok(year
+        .get(day)
+        .ok_or(format!("day {day} of {} is not solved yet", year.year))?)
+}
+
+/// Parse a day number, whether or not it has a solver yet.
+fn day_number(day: &str) -> CliResult<u8> {
+    match day.parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => usage(format!("invalid day `{day}`")),
+    }
+}
+
+fn input_source(args: &Args, solvers: &[&dyn Solver]) -> CliResult<InputSource> {
+    let source = InputSource::resolve(args.input);
+    if solvers.len() > 1 && !source.is_per_day() {
+        return usage("--input must be a directory when running all days");
+    }
+    if let InputSource::Dir(dir) = &source {
+        hint_legacy(args, dir, "txt");
+    }
+    Ok(source)
+}
+
+/// Where answers are recorded.
+fn answers_dir(args: &Args) -> PathBuf {
+    let dir = Answers::dir();
+    hint_legacy(args, &dir, "toml");
+    dir
+}
+
+/// Point out files in `dir` that are still in the flat layout from before
+/// years had folders, since they are no longer read from there.
+fn hint_legacy(args: &Args, dir: &Path, extension: &str) {
+    if args.year() != input::LEGACY_YEAR {
+        return;
+    }
+    if let [(_, first), rest @ ..] = &input::legacy_files(dir, extension)[..] {
+        let others = match rest.len() {
+            0 => String::new(),
+            1 => " and 1 other file".into(),
+            n => format!(" and {n} other files"),
+        };
+        eprintln!(
+            "note: found {}{others} in the old layout, which is no longer read; \
+             run `aoc migrate` to move them",
+            first.display()
+        );
+    }
+}
+
+fn run(args: &Args) -> CliResult {
+    if args.visualize {
+        return visualize(args);
+    }
+    if args.fps.is_some() || args.frames.is_some() {
+        return usage("--fps and --frames need --visualize");
+    }
+
+    let solvers = select_days(args, false)?;
+    let source = input_source(args, &solvers)?;
+    let year = args.year();
+
+    let jobs = args
+        .jobs
+        .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()));
+
+    // A missing input only fails the run when it is the one day asked for.
+    let mut inputs = Vec::with_capacity(solvers.len());
+    let mut missing = Vec::new();
+    for &solver in &solvers {
+        match source.read(year, solver.day()) {
+            Ok(input) => inputs.push((solver, input)),
+            Err(e) if solvers.len() == 1 => return Err(e.into()),
+            Err(e) => missing.push((solver.day(), e.to_string())),
+        }
+    }
+
+    if let Some(path) = args.dump_image {
+        let [(solver, input)] = &inputs[..] else {
+            return usage("--dump-image needs a single day");
+        };
+        dump_image(solutions(args)?, *solver, input, Path::new(path))?;
+    }
+
+    runner::silence_panics();
+    let results = runner::run_days(&inputs, args.part, jobs);
+    let records = || report::records(&results, &missing, args.part, alloc_stats::ENABLED);
+    match args.format {
+        Format::Text => print_summary(&results, &missing),
+        Format::Json => print!("{}", report::to_json(&records())),
+        Format::Csv => print!("{}", report::to_csv(&records())),
+    }
+
+    Ok(())
+}
+
+/// Save a picture of the day's puzzle state, in the format `path`'s
+/// extension names.
+fn dump_image(year: &Year, solver: &dyn Solver, input: &str, path: &Path) -> CliResult {
+    let day = solver.day();
+    let draw = (year.picture)(day).ok_or(format!("day {day} has no image"))?;
+    let picture = draw(input).map_err(|e| e.for_day(day))?;
+    picture.save(path)?;
+    eprintln!(
+        "wrote {}x{} image to {}",
+        picture.width(),
+        picture.height(),
+        path.display()
+    );
+    Ok(())
+}
+
+/// Play a day's simulation in the terminal, or dump its frames with `--frames`.
+fn visualize(args: &Args) -> CliResult {
+    let solvers = select_days(args, false)?;
+    let [solver] = solvers[..] else {
+        return usage("--visualize needs a single day");
+    };
+    let day = solver.day();
+    let animation =
+        (solutions(args)?.animation)(day).ok_or(format!("day {day} has no visualization"))?;
+    let input = input_source(args, &solvers)?.read(args.year(), day)?;
+    let tag = |e: ParseError| e.for_day(day);
+
+    match args.frames {
+        Some(dir) => {
+            let mut dump = FrameDump::new(dir)?;
+            (animation.visualize)(&input, &mut dump).map_err(tag)?;
+            println!("wrote {} frames to {dir}", dump.finish()?);
+        }
+        None => {
+            let fps = args.fps.unwrap_or(visualize::DEFAULT_FPS);
+            let mut player = Player::new(animation.palette, fps);
+            (animation.visualize)(&input, &mut player).map_err(tag)?;
+            player.finish()?;
+        }
+    }
+
+    Ok(())
+}
+
+fn record(args: &Args) -> CliResult {
+    let solvers = select_days(args, false)?;
+    let source = input_source(args, &solvers)?;
+    let (dir, year) = (answers_dir(args), args.year());
+
+    runner::silence_panics();
+    for solver in solvers {
+        let day = solver.day();
+        let run = runner::run_day(solver, &source.read(year, day)?, args.part);
+        if let Outcome::Invalid(e) = &run.part1 {
+            return Err(e.clone().into());
+        }
+
+        let mut answers = Answers::load(&dir, year, day)?;
+        for part in [1, 2] {
+            match run.part(part) {
+                Outcome::Solved(answer) => {
+                    println!("day {day} part {part}: recorded {answer}");
+                    answers.set_part(part, answer.clone());
+                }
+                Outcome::Panicked(msg) => println!("day {day} part {part}: panicked: {msg}"),
+                Outcome::Unimplemented => println!("day {day} part {part}: unimplemented"),
+                Outcome::Invalid(_) | Outcome::Skipped => {}
+            }
+        }
+        answers.save(&dir, year, day)?;
+    }
+
+    Ok(())
+}
+
+fn verify(args: &Args) -> CliResult {
+    let solvers = select_days(args, true)?;
+    let source = input_source(args, &solvers)?;
+    let (dir, year) = (answers_dir(args), args.year());
+
+    runner::silence_panics();
+    let mut rows = Vec::new();
+    let mut failed = false;
+    let mut verified = 0;
+    for solver in solvers {
+        let day = solver.day();
+        let input = match source.read(year, day) {
+            Ok(input) => input,
+            Err(e) => {
+                failed = true;
+                rows.push([
+                    day.to_string(),
+                    "-".into(),
+                    "no input".into(),
+                    e.to_string(),
+                ]);
+                continue;
+            }
+        };
+        let answers = Answers::load(&dir, year, day)?;
+        let run = runner::run_day(solver, &input, None);
+        if let Outcome::Invalid(e) = &run.part1 {
+            failed = true;
+            rows.push([
+                day.to_string(),
+                "-".into(),
+                "PARSE ERROR".into(),
+                e.to_string(),
+            ]);
+            continue;
+        }
+
+        for part in [1, 2] {
+            let (status, detail) = match (run.part(part), answers.part(part)) {
+                (Outcome::Solved(got), Some(expected)) if got == expected => {
+                    verified += 1;
+                    ("ok", got.clone())
+                }
+                (Outcome::Solved(got), Some(expected)) => {
+                    failed = true;
+                    ("MISMATCH", format!("got {got}, expected {expected}"))
+                }
+                (Outcome::Solved(got), None) => ("no answer", got.clone()),
+                (Outcome::Panicked(msg), _) => {
+                    failed = true;
+                    ("PANIC", msg.clone())
+                }
+                (Outcome::Unimplemented, _) => ("unimplemented", "-".into()),
+                (Outcome::Invalid(_) | Outcome::Skipped, _) => continue,
+            };
+            rows.push([day.to_string(), part.to_string(), status.into(), detail]);
+        }
+    }
+
+    print_table(["Day", "Part", "Status", "Detail"], &rows, &[0, 1]);
+
+    if failed {
+        return Err("verification failed".into());
+    }
+    if verified == 0 {
+        return Err("no answers were verified".into());
+    }
+    Ok(())
+}
+
+/// The single `<day>` argument of commands that work on unsolved days too.
+fn new_day(args: &Args) -> CliResult<u8> {
+    match args.positional.as_slice() {
+        [day] => day_number(day),
+        [] => usage("missing day"),
+        [_, extra, ..] => usage(format!("unexpected argument `{extra}`")),
+    }
+}
+
+/// The input directory for commands that write inputs rather than read
+/// them. It is created as needed, so it need not exist yet.
+fn input_dir(args: &Args) -> CliResult<InputSource> {
+    match args.input {
+        Some("-") => usage("--input must be a directory"),
+        Some(dir) => Ok(InputSource::Dir(dir.into())),
+        None => Ok(InputSource::resolve(None)),
+    }
+}
+
+/// Where the input for `day` goes when it is created rather than read.
+fn input_path(args: &Args, day: u8) -> CliResult<PathBuf> {
+    Ok(input_dir(args)?
+        .path(args.year(), day)
+        .expect("directory sources have per-day paths"))
+}
+
+/// Move inputs and answers left in the layout from before years had their
+/// own folders to where they are looked for now.
+fn migrate(args: &Args) -> CliResult {
+    if let Some(extra) = args.positional.first() {
+        return usage(format!("unexpected argument `{extra}`"));
+    }
+    if args.year() != input::LEGACY_YEAR {
+        return usage(format!(
+            "only {} files were kept in the old layout",
+            input::LEGACY_YEAR
+        ));
+    }
+
+    let dir = Answers::dir();
+    let mut moved = Answers::migrate_legacy(&dir)?;
+    let source = input_dir(args)?;
+    let InputSource::Dir(dir) = &source else {
+        unreachable!("input_dir always gives a directory");
+    };
+    let path = |day| {
+        source
+            .path(input::LEGACY_YEAR, day)
+            .expect("directory sources have per-day paths")
+    };
+    let inputs = input::migrate_legacy(dir, "txt", path)
+        .map_err(|e| format!("failed to move inputs in {}: {e}", dir.display()))?;
+    moved.extend(inputs);
+
+    if moved.is_empty() {
+        println!("nothing to move");
+    }
+    for (old, new) in moved {
+        println!("moved {} to {}", old.display(), new.display());
+    }
+    Ok(())
+}
+
+fn new(args: &Args) -> CliResult {
+    let day = new_day(args)?;
+    let title = args.title.unwrap_or("TODO");
+    let input = input_path(args, day)?;
+    let year = args.year();
+
+    scaffold::scaffold(&scaffold::year_dir(year), &input, year, day, title)?;
+    println!(
+        "day {day}: created src/{}/day{day:02}.rs and {}",
+        Year::module(year),
+        input.display()
+    );
+    Ok(())
+}
+
+fn watch(args: &Args) -> CliResult {
+    let solvers = select_days(args, false)?;
+    let [solver] = solvers.as_slice() else {
+        return usage("watch takes a single day");
+    };
+    let (year, day) = (args.year(), solver.day());
+    let Some(input) = input_source(args, &solvers)?.path(year, day) else {
+        return usage("--input must be a file or directory when watching");
+    };
+    let source = scaffold::day_source(&scaffold::year_dir(year), day);
+
+    let mut watcher = Watcher::new([source.clone(), input.clone()]);
+    println!(
+        "watching {} and {}; press Ctrl-C to stop",
+        source.display(),
+        input.display()
+    );
+
+    let mut previous = Vec::new();
+    loop {
+        if let Some(records) = watch_cycle(year, day, &input, &previous)? {
+            previous = records;
+        }
+        let changed = loop {
+            std::thread::sleep(WATCH_INTERVAL);
+            let changed = watcher.changed();
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+        for path in changed {
+            println!("\nchanged: {}", path.display());
+        }
+    }
+}
+
+/// Test and run `day` of `year` once, printing how its answers compare with
+/// `previous`. Returns the new answers, or `None` if it did not build.
+fn watch_cycle(
+    year: u16,
+    day: u8,
+    input: &Path,
+    previous: &[Record],
+) -> CliResult<Option<Vec<Record>>> {
+    println!("== day {day} of {year} ==");
+    let (passed, output) = watch::run_tests(year, day)?;
+    if passed {
+        println!("examples: ok");
+    } else {
+        println!("examples: FAILED\n{}", output.trim_end());
+    }
+
+    let records = match watch::run_input(year, day, input)? {
+        Ok(records) => records,
+        Err(output) => {
+            println!("{}", output.trim_end());
+            return Ok(None);
+        }
+    };
+    for record in &records {
+        println!(
+            "part {}: {}{}  {}",
+            record.part,
+            watch::outcome(record),
+            watch::compare(previous, record),
+            fmt_duration(record.duration)
+        );
+    }
+    Ok(Some(records))
+}
+
+fn generate(args: &Args) -> CliResult {
+    let day = new_day(args)?;
+    let year = solutions(args)?;
+    let Some(size) = args.size.or_else(|| (year.default_size)(day)) else {
+        return Err(format!("day {day} has no input generator").into());
+    };
+    let input = (year.generate)(day, args.seed.unwrap_or(0), size)
+        .ok_or(format!("day {day} has no input generator"))?;
+    print!("{input}");
+    Ok(())
+}
+
+fn fetch(args: &Args) -> CliResult {
+    let day = new_day(args)?;
+    let path = input_path(args, day)?;
+
+    match fetch::fetch_input(&SystemHttp, &Site::from_env()?, args.year(), day, &path)? {
+        Fetched::Cached => println!("day {day}: already have {}", path.display()),
+        Fetched::Downloaded => println!("day {day}: saved {}", path.display()),
+    }
+    Ok(())
+}
+
+fn submit(args: &Args) -> CliResult {
+    let (solver, part) = match args.positional.as_slice() {
+        [day, "1"] => (solver(args, day)?, 1),
+        [day, "2"] => (solver(args, day)?, 2),
+        [_, part] => return usage(format!("invalid part `{part}`")),
+        [_, _, extra, ..] => return usage(format!("unexpected argument `{extra}`")),
+        _ => return usage("missing day or part"),
+    };
+    let (year, day) = (args.year(), solver.day());
+    let source = input_source(args, &[solver])?;
+
+    runner::silence_panics();
+    let answer = match runner::run_day(solver, &source.read(year, day)?, Some(part)).part(part) {
+        Outcome::Solved(answer) => answer.clone(),
+        Outcome::Panicked(msg) => {
+            return Err(format!("day {day} part {part} panicked: {msg}").into())
+        }
+        Outcome::Unimplemented => {
+            return Err(format!("day {day} part {part} is not implemented yet").into())
+        }
+        Outcome::Invalid(e) => return Err(e.clone().into()),
+        Outcome::Skipped => unreachable!("the submitted part is always run"),
+    };
+
+    let dir = answers_dir(args);
+    let mut answers = Answers::load(&dir, year, day)?;
+    let now = unix_now();
+    if let Err(refusal) = submit::check(&answers, part, &answer, now) {
+        return Err(format!("not submitting {answer}: {refusal}").into());
+    }
+
+    let reply = submit::submit(&SystemHttp, &Site::from_env()?, year, day, part, &answer)?;
+    submit::record(&mut answers, part, &answer, &reply, now);
+    answers.save(&dir, year, day)?;
+
+    println!("day {day} part {part}: {answer} is {}", reply.verdict);
+    if let Some(wait) = reply.wait {
+        println!("wait {}s before the next submission", wait.as_secs());
+    }
+    if reply.verdict != Verdict::Correct {
+        return Err("answer not accepted".into());
+    }
+    Ok(())
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+fn bench(args: &Args) -> CliResult {
+    let solvers = select_days(args, false)?;
+    let source = input_source(args, &solvers)?;
+    let baseline = args
+        .baseline
+        .map(|path| Baseline::load(Path::new(path), args.year()))
+        .transpose()?;
+    let iterations = args.iterations.unwrap_or(BENCH_ITERATIONS);
+
+    runner::silence_panics();
+    let mut results = Vec::with_capacity(solvers.len());
+    for solver in solvers {
+        let input = source.read(args.year(), solver.day())?;
+        results.push(bench::bench_day(solver, &input, iterations, args.part));
+    }
+
+    let regressions = print_bench(args.year(), &results, baseline.as_ref());
+
+    if let Some(path) = args.save_baseline {
+        let mut saved = Baseline::default();
+        for result in &results {
+            saved.record(args.year(), result);
+        }
+        saved.save(Path::new(path))?;
+        println!("saved baseline to {path}");
+    }
+
+    if regressions > 0 {
+        return Err(format!("{regressions} stage(s) regressed against the baseline").into());
+    }
+    Ok(())
+}
+
+/// Print benchmark results, returning how many stages regressed.
+fn print_bench(year: u16, results: &[DayBench], baseline: Option<&Baseline>) -> usize {
+    let mut regressions = 0;
+    let mut rows = Vec::new();
+
+    for result in results {
+        for stage in &result.stages {
+            let stats = match &stage.result {
+                Ok(stats) => stats,
+                Err(msg) => {
+                    let mut row: [String; 7] = Default::default();
+                    row[0] = result.day.to_string();
+                    row[1] = stage.stage.name().to_string();
+                    row[6] = msg.clone();
+                    rows.push(row);
+                    continue;
+                }
+            };
+
+            let old = baseline.and_then(|b| b.get(year, result.day, stage.stage));
+            let change = match old {
+                Some(old) if !old.is_zero() => {
+                    let ratio = stats.median.as_secs_f64() / old.as_secs_f64() - 1.0;
+                    let regressed = ratio > REGRESSION_THRESHOLD;
+                    regressions += regressed as usize;
+                    format!(
+                        "{:+.1}%{}",
+                        ratio * 100.0,
+                        if regressed { " REGRESSED" } else { "" }
+                    )
+                }
+                _ => "-".to_string(),
+            };
+
+            rows.push([
+                result.day.to_string(),
+                stage.stage.name().to_string(),
+                fmt_duration(stats.min),
+                fmt_duration(stats.median),
+                fmt_duration(stats.max),
+                old.map(fmt_duration).unwrap_or_else(|| "-".to_string()),
+                change,
+            ]);
+        }
+    }
+
+    print_table(
+        ["Day", "Stage", "Min", "Median", "Max", "Baseline", "Change"],
+        &rows,
+        &[0, 2, 3, 4, 5],
+    );
+
+    regressions
+}
+
+fn fmt_duration(d: Duration) -> String {
+    format!("{d:.2?}")
+}
+
+fn print_summary(results: &[DayRun], missing: &[(u8, String)]) {
+    let cell = |outcome: &Outcome| match outcome {
+        Outcome::Solved(answer) => answer.clone(),
+        Outcome::Panicked(msg) => format!("FAILED: {msg}"),
+        Outcome::Unimplemented => "unimplemented".to_string(),
+        Outcome::Invalid(_) => "parse error".to_string(),
+        Outcome::Skipped => "-".to_string(),
+    };
+    let mut rows: Vec<(u8, [String; 4])> = results
+        .iter()
+        .map(|r| {
+            let row = [
+                r.day.to_string(),
+                cell(&r.part1),
+                cell(&r.part2),
+                fmt_duration(r.elapsed),
+            ];
+            (r.day, row)
+        })
+        .collect();
+    for (day, _) in missing {
+        let no_input = || "no input".to_string();
+        rows.push((*day, [day.to_string(), no_input(), no_input(), "-".into()]));
+    }
+    rows.sort_by_key(|(day, _)| *day);
+    let rows: Vec<_> = rows.into_iter().map(|(_, row)| row).collect();
+
+    print_table(["Day", "Part 1", "Part 2", "Time"], &rows, &[0, 3]);
+    if alloc_stats::ENABLED {
+        println!();
+        print_allocs(results);
+    }
+
+    for result in results {
+        if let Outcome::Invalid(e) = &result.part1 {
+            eprintln!("error: {e}");
+        }
+    }
+    for (_, e) in missing {
+        eprintln!("error: {e}");
+    }
+}
+
+/// Print what each stage of each day allocated.
+fn print_allocs(results: &[DayRun]) {
+    let stages = [("parse", None), ("part 1", Some(1)), ("part 2", Some(2))];
+    let mut rows = Vec::new();
+    for run in results {
+        for ((stage, part), stats) in stages.into_iter().zip(run.allocs) {
+            let ran = match part {
+                None => !matches!(run.part1, Outcome::Invalid(_)),
+                Some(p) => run.part(p).is_run(),
+            };
+            if ran {
+                rows.push([
+                    run.day.to_string(),
+                    stage.to_string(),
+                    stats.allocations.to_string(),
+                    fmt_bytes(stats.bytes),
+                    fmt_bytes(stats.peak),
+                ]);
+            }
+        }
+    }
+
+    print_table(
+        ["Day", "Stage", "Allocations", "Allocated", "Peak live"],
+        &rows,
+        &[0, 2, 3, 4],
+    );
+}
+
+fn fmt_bytes(bytes: u64) -> String {
+    match bytes {
+        0..1024 => format!("{bytes} B"),
+        1024..0x10_0000 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0)),
+    }
+}
+
+/// Print a table, right-aligning the columns listed in `right`.
+fn print_table<const N: usize>(header: [&str; N], rows: &[[String; N]], right: &[usize]) {
+    let header = header.map(String::from);
+    let mut widths = header.clone().map(|h| h.len());
+    for row in rows {
+        for (width, value) in widths.iter_mut().zip(row) {
+            *width = (*width).max(value.len());
+        }
+    }
+
+    for row in std::iter::once(&header).chain(rows) {
+        let line = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(i, (value, width))| match right.contains(&i) {
+                true => format!("{value:>width$}"),
+                false => format!("{value:<width$}"),
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
 }
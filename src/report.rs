@@ -0,0 +1,440 @@
+// Results of a run in a stable, machine-readable shape: one record per day
+// and part, written as a JSON array or as CSV with a header row. Builds that
+// count allocations add what each part allocated.
+
+use std::fmt::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::alloc_stats::AllocStats;
+use crate::runner::{DayRun, Outcome};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// The aligned table meant for people.
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("invalid format `{s}`; expected json, csv or text")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    /// The part panicked.
+    Failed,
+    Unimplemented,
+    ParseError,
+    NoInput,
+}
+
+impl Status {
+    const ALL: [Status; 5] = [
+        Status::Solved,
+        Status::Failed,
+        Status::Unimplemented,
+        Status::ParseError,
+        Status::NoInput,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Failed => "failed",
+            Status::Unimplemented => "unimplemented",
+            Status::ParseError => "parse_error",
+            Status::NoInput => "no_input",
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// One part of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// Present only when solved.
+    pub answer: Option<String>,
+    pub duration: Duration,
+    pub status: Status,
+    /// What went wrong, for anything but a solved or unimplemented part.
+    pub error: Option<String>,
+    /// Present only when allocations are counted and the part ran.
+    pub allocs: Option<PartAllocs>,
+}
+
+/// What one part of a day allocated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PartAllocs {
+    /// Parsing the input, which both parts share.
+    pub parse: AllocStats,
+    pub part: AllocStats,
+}
+
+/// The CSV columns every record has.
+const CSV_HEADER: &str = "day,part,answer,duration_ns,status,error";
+
+/// The CSV columns added when any record has allocation counts.
+const CSV_ALLOC_HEADER: &str = "parse_allocations,parse_bytes,parse_peak,allocations,bytes,peak";
+
+/// Records for every part that was run, plus the requested parts of days
+/// whose input could not be read, in day and part order. Parts that ran get
+/// their allocation counts if `with_allocs`.
+pub fn records(
+    runs: &[DayRun],
+    missing: &[(u8, String)],
+    part: Option<u8>,
+    with_allocs: bool,
+) -> Vec<Record> {
+    let mut records = Vec::new();
+    for run in runs {
+        for p in [1, 2] {
+            let allocs = PartAllocs {
+                parse: run.allocs[0],
+                part: run.allocs[usize::from(p)],
+            };
+            let allocs = (with_allocs && run.part(p).is_run()).then_some(allocs);
+            let (status, answer, error) = match run.part(p) {
+                Outcome::Solved(answer) => (Status::Solved, Some(answer.clone()), None),
+                Outcome::Panicked(msg) => (Status::Failed, None, Some(msg.clone())),
+                Outcome::Unimplemented => (Status::Unimplemented, None, None),
+                Outcome::Invalid(e) => (Status::ParseError, None, Some(e.to_string())),
+                Outcome::Skipped => continue,
+            };
+            records.push(Record {
+                day: run.day,
+                part: p,
+                answer,
+                duration: run.part_time(p),
+                status,
+                error,
+                allocs,
+            });
+        }
+    }
+    for (day, error) in missing {
+        for p in [1, 2]
+            .into_iter()
+            .filter(|&p| part.is_none_or(|only| only == p))
+        {
+            records.push(Record {
+                day: *day,
+                part: p,
+                answer: None,
+                duration: Duration::ZERO,
+                status: Status::NoInput,
+                error: Some(error.clone()),
+                allocs: None,
+            });
+        }
+    }
+
+    records.sort_by_key(|r| (r.day, r.part));
+    records
+}
+
+/// A JSON array of objects with the keys `day`, `part`, `answer`,
+/// `duration_ns`, `status` and `error`, plus `allocs` with `parse` and `part`
+/// counts when the record has them.
+pub fn to_json(records: &[Record]) -> String {
+    let optional = |value: &Option<String>| match value {
+        Some(value) => json_string(value),
+        None => "null".to_string(),
+    };
+
+    let mut out = String::from("[");
+    for (i, r) in records.iter().enumerate() {
+        out.push_str(if i == 0 { "\n  " } else { ",\n  " });
+        write!(
+            out,
+            r#"{{"day": {}, "part": {}, "answer": {}, "duration_ns": {}, "status": "{}", "error": {}"#,
+            r.day,
+            r.part,
+            optional(&r.answer),
+            r.duration.as_nanos(),
+            r.status.name(),
+            optional(&r.error)
+        )
+        .unwrap();
+        if let Some(allocs) = &r.allocs {
+            write!(
+                out,
+                r#", "allocs": {{"parse": {}, "part": {}}}"#,
+                json_allocs(&allocs.parse),
+                json_allocs(&allocs.part)
+            )
+            .unwrap();
+        }
+        out.push('}');
+    }
+    out.push_str(if records.is_empty() { "]\n" } else { "\n]\n" });
+    out
+}
+
+fn json_allocs(stats: &AllocStats) -> String {
+    format!(
+        r#"{{"allocations": {}, "bytes": {}, "peak": {}}}"#,
+        stats.allocations, stats.bytes, stats.peak
+    )
+}
+
+/// CSV with a `day,part,answer,duration_ns,status,error` header, followed by
+/// the [allocation columns](CSV_ALLOC_HEADER) when any record has counts.
+pub fn to_csv(records: &[Record]) -> String {
+    let with_allocs = records.iter().any(|r| r.allocs.is_some());
+    let mut out = String::from(CSV_HEADER);
+    if with_allocs {
+        write!(out, ",{CSV_ALLOC_HEADER}").unwrap();
+    }
+    out.push('\n');
+
+    for r in records {
+        write!(
+            out,
+            "{},{},{},{},{},{}",
+            r.day,
+            r.part,
+            csv_field(r.answer.as_deref().unwrap_or("")),
+            r.duration.as_nanos(),
+            r.status.name(),
+            csv_field(r.error.as_deref().unwrap_or(""))
+        )
+        .unwrap();
+        match &r.allocs {
+            Some(PartAllocs { parse, part }) => write!(
+                out,
+                ",{},{},{},{},{},{}",
+                parse.allocations, parse.bytes, parse.peak, part.allocations, part.bytes, part.peak
+            )
+            .unwrap(),
+            None if with_allocs => out.push_str(",,,,,,"),
+            None => {}
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Read back what [`to_csv`] wrote; `None` if it is not in that shape.
+pub fn from_csv(text: &str) -> Option<Vec<Record>> {
+    let mut lines = text.lines();
+    let header = lines.next()?;
+    let with_allocs = match header.strip_prefix(CSV_HEADER)? {
+        "" => false,
+        rest if rest.strip_prefix(',') == Some(CSV_ALLOC_HEADER) => true,
+        _ => return None,
+    };
+
+    let mut records = Vec::new();
+    let mut pending = String::new();
+    for line in lines {
+        // A quoted field can span lines.
+        if !pending.is_empty() {
+            pending.push('\n');
+        }
+        pending.push_str(line);
+        let Some(fields) = csv_fields(&pending) else {
+            continue;
+        };
+        let (fields, counts) = match with_allocs {
+            true if fields.len() == 12 => fields.split_at(6),
+            false if fields.len() == 6 => (&fields[..], &[][..]),
+            _ => return None,
+        };
+        let [day, part, answer, duration, status, error] =
+            <[String; 6]>::try_from(fields.to_vec()).ok()?;
+        let optional = |s: String| (!s.is_empty()).then_some(s);
+        records.push(Record {
+            day: day.parse().ok()?,
+            part: part.parse().ok()?,
+            answer: optional(answer),
+            duration: Duration::from_nanos(duration.parse().ok()?),
+            status: *Status::ALL.iter().find(|s| s.name() == status)?,
+            error: optional(error),
+            allocs: csv_allocs(counts)?,
+        });
+        pending.clear();
+    }
+
+    pending.is_empty().then_some(records)
+}
+
+/// The allocation columns of a CSV record: `Some(None)` when they are absent
+/// or empty, `None` when they do not parse.
+fn csv_allocs(counts: &[String]) -> Option<Option<PartAllocs>> {
+    if counts.iter().all(|c| c.is_empty()) {
+        return Some(None);
+    }
+    let counts: Vec<u64> = counts
+        .iter()
+        .map(|c| c.parse().ok())
+        .collect::<Option<_>>()?;
+    let stats = |c: &[u64]| AllocStats {
+        allocations: c[0],
+        bytes: c[1],
+        peak: c[2],
+    };
+    Some(Some(PartAllocs {
+        parse: stats(&counts[..3]),
+        part: stats(&counts[3..]),
+    }))
+}
+
+/// Split one CSV record, or `None` if a quoted field is still open.
+fn csv_fields(line: &str) -> Option<Vec<String>> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => fields.push(String::new()),
+            (c, _) => fields.last_mut().unwrap().push(c),
+        }
+    }
+    (!quoted).then_some(fields)
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Quote a field if it holds a separator, quote or line break.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ParseError;
+
+    fn runs() -> Vec<DayRun> {
+        vec![
+            DayRun {
+                day: 17,
+                part1: Outcome::Solved("4,6,3".into()),
+                part2: Outcome::Unimplemented,
+                part_times: [Duration::from_micros(12), Duration::from_nanos(500)],
+                elapsed: Duration::from_micros(20),
+                allocs: Default::default(),
+            },
+            DayRun {
+                day: 5,
+                part1: Outcome::Invalid(ParseError::new(2, 3, "integer", "x4").for_day(5)),
+                part2: Outcome::Invalid(ParseError::new(2, 3, "integer", "x4").for_day(5)),
+                part_times: [Duration::ZERO; 2],
+                elapsed: Duration::from_micros(3),
+                allocs: Default::default(),
+            },
+        ]
+    }
+
+    #[test]
+    fn writes_json() {
+        let missing = [(9, "input file not found: inputs/day9.txt".to_string())];
+        let records = records(&runs(), &missing, Some(1), false);
+        assert_eq!(
+            to_json(&records),
+            r#"[
+  {"day": 5, "part": 1, "answer": null, "duration_ns": 0, "status": "parse_error", "error": "day 5, line 2, column 3: expected integer, found `x4`"},
+  {"day": 5, "part": 2, "answer": null, "duration_ns": 0, "status": "parse_error", "error": "day 5, line 2, column 3: expected integer, found `x4`"},
+  {"day": 9, "part": 1, "answer": null, "duration_ns": 0, "status": "no_input", "error": "input file not found: inputs/day9.txt"},
+  {"day": 17, "part": 1, "answer": "4,6,3", "duration_ns": 12000, "status": "solved", "error": null},
+  {"day": 17, "part": 2, "answer": null, "duration_ns": 500, "status": "unimplemented", "error": null}
+]
+"#
+        );
+        assert_eq!(to_json(&[]), "[]\n");
+    }
+
+    #[test]
+    fn writes_csv() {
+        let mut records = records(&runs(), &[], None, false);
+        records[0].error = Some("said \"no\"".into());
+        assert_eq!(
+            to_csv(&records),
+            "\
+day,part,answer,duration_ns,status,error
+5,1,,0,parse_error,\"said \"\"no\"\"\"
+5,2,,0,parse_error,\"day 5, line 2, column 3: expected integer, found `x4`\"
+17,1,\"4,6,3\",12000,solved,
+17,2,,500,unimplemented,
+"
+        );
+        assert_eq!(from_csv(&to_csv(&records)), Some(records));
+        assert_eq!(from_csv("day,part\n"), None);
+    }
+
+    #[test]
+    fn round_trips_allocation_counts() {
+        let stats = |allocations, bytes, peak| AllocStats {
+            allocations,
+            bytes,
+            peak,
+        };
+        let mut runs = runs();
+        runs[0].allocs = [stats(3, 120, 96), stats(7, 4096, 2048), stats(0, 0, 0)];
+        let records = records(&runs, &[], None, true);
+        // Day 5 did not parse, so neither of its parts has counts.
+        assert_eq!(records[0].allocs, None);
+        assert_eq!(
+            records[3].allocs,
+            Some(PartAllocs {
+                parse: stats(3, 120, 96),
+                part: stats(0, 0, 0),
+            })
+        );
+
+        let csv = to_csv(&records);
+        assert!(csv.starts_with(&format!("{CSV_HEADER},{CSV_ALLOC_HEADER}\n")));
+        assert!(csv.contains("\n17,1,\"4,6,3\",12000,solved,,3,120,96,7,4096,2048\n"));
+        assert!(csv.contains("\n5,1,,0,parse_error,\"day 5, line 2, column 3: expected integer, found `x4`\",,,,,,\n"));
+        assert_eq!(from_csv(&csv), Some(records.clone()));
+
+        let json = to_json(&records);
+        assert!(json.contains(
+            r#""error": null, "allocs": {"parse": {"allocations": 3, "bytes": 120, "peak": 96}, "part": {"allocations": 7, "bytes": 4096, "peak": 2048}}}"#
+        ));
+    }
+}
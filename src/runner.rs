@@ -0,0 +1,212 @@
+// Runs a day's solver on one input, timing it and containing panics so that
+// one broken day does not take down a whole run. Several days can be run at
+// once on a small pool of threads.
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::alloc_stats::{self, AllocStats};
+use crate::error::ParseError;
+use crate::Solver;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(String),
+    /// The solver panicked; holds the panic message.
+    Panicked(String),
+    /// The part is still a `todo!()`.
+    Unimplemented,
+    /// The input could not be parsed.
+    Invalid(ParseError),
+    /// The part was not requested.
+    Skipped,
+}
+
+impl Outcome {
+    pub fn answer(&self) -> Option<&str> {
+        match self {
+            Outcome::Solved(answer) => Some(answer),
+            _ => None,
+        }
+    }
+
+    /// Whether the part was actually run, whatever its result.
+    pub fn is_run(&self) -> bool {
+        !matches!(self, Outcome::Invalid(_) | Outcome::Skipped)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DayRun {
+    pub day: u8,
+    pub part1: Outcome,
+    pub part2: Outcome,
+    /// Time spent in each part, excluding parsing; zero for a part not run.
+    pub part_times: [Duration; 2],
+    /// Wall time for the whole day, parsing included.
+    pub elapsed: Duration,
+    /// What parsing, part 1 and part 2 allocated; all zero unless built with
+    /// the `alloc-stats` feature.
+    pub allocs: [AllocStats; 3],
+}
+
+impl DayRun {
+    pub fn part(&self, part: u8) -> &Outcome {
+        match part {
+            1 => &self.part1,
+            _ => &self.part2,
+        }
+    }
+
+    pub fn part_time(&self, part: u8) -> Duration {
+        self.part_times[usize::from(part.clamp(1, 2)) - 1]
+    }
+}
+
+/// Parse `input` and solve the requested part, or both when `part` is `None`.
+pub fn run_day(solver: &dyn Solver, input: &str, part: Option<u8>) -> DayRun {
+    let start = Instant::now();
+    let mut part_times = [Duration::ZERO; 2];
+    let mut allocs = [AllocStats::default(); 3];
+    let (parsed, parse_allocs) = alloc_stats::measure(|| catch(|| solver.parse(input)));
+    allocs[0] = parse_allocs;
+    let (part1, part2) = match parsed {
+        Ok(Ok(parsed)) => {
+            let mut solve = |p: u8, f: &dyn Fn(&dyn Any) -> String| match part {
+                Some(only) if only != p => Outcome::Skipped,
+                _ => {
+                    let part_start = Instant::now();
+                    let (answer, part_allocs) =
+                        alloc_stats::measure(|| catch(|| f(parsed.as_ref())));
+                    part_times[usize::from(p) - 1] = part_start.elapsed();
+                    allocs[usize::from(p)] = part_allocs;
+                    match answer {
+                        Ok(answer) => Outcome::Solved(answer),
+                        Err(msg) => panicked(msg),
+                    }
+                }
+            };
+            (
+                solve(1, &|parsed| solver.part1(parsed)),
+                solve(2, &|parsed| solver.part2(parsed)),
+            )
+        }
+        Ok(Err(e)) => (Outcome::Invalid(e.clone()), Outcome::Invalid(e)),
+        Err(msg) => (panicked(msg.clone()), panicked(msg)),
+    };
+
+    DayRun {
+        day: solver.day(),
+        part1,
+        part2,
+        part_times,
+        elapsed: start.elapsed(),
+        allocs,
+    }
+}
+
+/// Run every `(solver, input)` pair on up to `jobs` threads, returning the
+/// results in day order.
+pub fn run_days(days: &[(&dyn Solver, String)], part: Option<u8>, jobs: usize) -> Vec<DayRun> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(days.len()));
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            scope.spawn(|| {
+                while let Some((solver, input)) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let run = run_day(*solver, input, part);
+                    results.lock().unwrap().push(run);
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|run| run.day);
+    results
+}
+
+/// Tell `todo!()` and `unimplemented!()` apart from real failures.
+fn panicked(msg: String) -> Outcome {
+    if msg.starts_with("not yet implemented") || msg.starts_with("not implemented") {
+        Outcome::Unimplemented
+    } else {
+        Outcome::Panicked(msg)
+    }
+}
+
+/// Replace the default panic hook so contained panics do not print a
+/// backtrace note in the middle of a report.
+pub fn silence_panics() {
+    panic::set_hook(Box::new(|_| {}));
+}
+
+pub(crate) fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solution;
+
+    struct Unfinished;
+
+    impl Solution for Unfinished {
+        const DAY: u8 = 25;
+        const TITLE: &'static str = "Unfinished";
+
+        type Input = u32;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.trim().parse().unwrap())
+        }
+
+        fn part1(input: &Self::Input) -> Self::Answer1 {
+            assert!(*input > 0, "input must be positive");
+            *input
+        }
+
+        fn part2(_input: &Self::Input) -> Self::Answer2 {
+            todo!()
+        }
+    }
+
+    #[test]
+    fn runs_in_parallel_in_day_order() {
+        let days: Vec<(&dyn Solver, String)> = vec![
+            (&Unfinished, "7".to_string()),
+            (crate::y2024::YEAR.get(1).unwrap(), "3 4\n4 3\n".to_string()),
+            (&Unfinished, "0".to_string()),
+        ];
+        let results = run_days(&days, None, 3);
+
+        let outcomes: Vec<_> = results
+            .iter()
+            .map(|run| (run.day, run.part1.clone(), run.part2.clone()))
+            .collect();
+        assert_eq!(
+            outcomes[0],
+            (1, Outcome::Solved("0".into()), Outcome::Solved("7".into()))
+        );
+        assert!(outcomes[1..].contains(&(25, Outcome::Solved("7".into()), Outcome::Unimplemented)));
+        assert!(outcomes[1..].contains(&(
+            25,
+            Outcome::Panicked("input must be positive".into()),
+            Outcome::Unimplemented
+        )));
+    }
+}
@@ -0,0 +1,299 @@
+// Generates the skeleton for a new day: `src/y<year>/dayNN.rs` with the usual
+// header comment, a `Solution` impl and an example-test module, registered in
+// `src/y<year>/mod.rs`, plus an empty input file to paste the puzzle input
+// into.
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::years::Year;
+
+/// Directory of `year`'s day modules in this crate's source tree.
+pub fn year_dir(year: u16) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join(Year::module(year))
+}
+
+/// The source file of `day` in `year_dir`.
+pub fn day_source(year_dir: &Path, day: u8) -> PathBuf {
+    year_dir.join(format!("day{day:02}.rs"))
+}
+
+/// Write and register day `day` of `year` in `year_dir`, and create an empty
+/// input at `input`. Nothing is overwritten: an existing day is an error, and
+/// an existing input is left alone.
+pub fn scaffold(
+    year_dir: &Path,
+    input: &Path,
+    year: u16,
+    day: u8,
+    title: &str,
+) -> Result<(), ScaffoldError> {
+    let source = day_source(year_dir, day);
+    if source.exists() {
+        return Err(ScaffoldError::Exists(source));
+    }
+
+    let mod_rs = year_dir.join("mod.rs");
+    let registry = std::fs::read_to_string(&mod_rs).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => ScaffoldError::NoYear(year),
+        _ => ScaffoldError::Io(mod_rs.clone(), e),
+    })?;
+    let registry = register(&registry, day).ok_or(ScaffoldError::Registry(mod_rs.clone(), day))?;
+
+    std::fs::write(&source, template(year, day, title))
+        .map_err(|e| ScaffoldError::Io(source.clone(), e))?;
+    if let Err(e) = std::fs::write(&mod_rs, registry) {
+        // Unregistered, the day would only block the next attempt.
+        let _ = std::fs::remove_file(&source);
+        return Err(ScaffoldError::Io(mod_rs, e));
+    }
+
+    if let Some(dir) = input.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| ScaffoldError::Io(dir.into(), e))?;
+    }
+    if !input.exists() {
+        std::fs::write(input, "").map_err(|e| ScaffoldError::Io(input.into(), e))?;
+    }
+    Ok(())
+}
+
+/// Add `pub mod dayNN;` and a registry entry for `day` to the text of a
+/// year's `mod.rs`. `None` if the day is already there or the file does not
+/// have the expected shape.
+fn register(mod_rs: &str, day: u8) -> Option<String> {
+    let name = format!("day{day:02}");
+    let module = format!("pub mod {name};");
+    let entry = format!("    &{name}::Day{day},");
+    if mod_rs.lines().any(|line| line == module || line == entry) {
+        return None;
+    }
+
+    let mut lines: Vec<String> = mod_rs.lines().map(String::from).collect();
+
+    // Modules are listed in name order, as rustfmt leaves them.
+    let last_mod = lines
+        .iter()
+        .rposition(|line| line.starts_with("pub mod "))?;
+    let at = lines[..=last_mod]
+        .iter()
+        .position(|line| {
+            let other = line
+                .strip_prefix("pub mod ")
+                .and_then(|l| l.strip_suffix(';'));
+            other.is_some_and(|other| other > name.as_str())
+        })
+        .unwrap_or(last_mod + 1);
+    lines.insert(at, module);
+
+    // The registry is in day order and its length is part of its type.
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub static REGISTRY:"))?;
+    let (head, tail) = lines[start].split_once("; ")?;
+    let (count, rest) = tail.split_once(']')?;
+    let count: usize = count.parse().ok()?;
+    lines[start] = format!("{head}; {}]{rest}", count + 1);
+
+    let end = start + lines[start..].iter().position(|line| line == "];")?;
+    let at = (start + 1..end)
+        .find(|&i| registered_day(&lines[i]).is_some_and(|n| n > day))
+        .unwrap_or(end);
+    lines.insert(at, entry);
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Some(text)
+}
+
+/// The day of a `    &dayNN::DayN,` registry line.
+fn registered_day(line: &str) -> Option<u8> {
+    let number = line.trim().strip_prefix("&day")?.split("::").next()?;
+    number.parse().ok()
+}
+
+fn template(year: u16, day: u8, title: &str) -> String {
+    format!(
+        r#"// Day {day}: {title}
+// https://adventofcode.com/{year}/day/{day}
+
+use crate::error::ParseError;
+use crate::Solution;
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const DAY: u8 = {day};
+    const TITLE: &'static str = {title:?};
+
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        parse_input(input)
+    }}
+
+    fn part1(_input: &Self::Input) -> Self::Answer1 {{
+        todo!()
+    }}
+
+    fn part2(_input: &Self::Input) -> Self::Answer2 {{
+        todo!()
+    }}
+}}
+
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {{
+    Ok(input.lines().map(String::from).collect())
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    const EXAMPLE: &str = "\
+";
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn part1_example() {{
+        assert_eq!(Day{day}::part1(&Day{day}::parse(EXAMPLE).unwrap()), 0);
+    }}
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn part2_example() {{
+        assert_eq!(Day{day}::part2(&Day{day}::parse(EXAMPLE).unwrap()), 0);
+    }}
+}}
+"#
+    )
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The day's source file is already there.
+    Exists(PathBuf),
+    /// The year has no module to add days to.
+    NoYear(u16),
+    /// The day is already registered, or `mod.rs` could not be edited.
+    Registry(PathBuf, u8),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => {
+                write!(
+                    f,
+                    "{} already exists; refusing to overwrite it",
+                    path.display()
+                )
+            }
+            ScaffoldError::NoYear(year) => write!(
+                f,
+                "there is no module for {year}: create src/{}/mod.rs and add it to years::YEARS",
+                Year::module(*year)
+            ),
+            ScaffoldError::Registry(path, day) => write!(
+                f,
+                "could not register day {day} in {}: already registered or unexpected layout",
+                path.display()
+            ),
+            ScaffoldError::Io(path, e) => write!(f, "failed to access {}: {e}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScaffoldError::Io(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::scratch_dir;
+
+    const MOD_RS: &str = "\
+pub mod day01;
+pub mod day02;
+pub mod day10;
+pub mod day30;
+
+use crate::Solver;
+
+/// Every solved day, in order.
+pub static REGISTRY: [&dyn Solver; 4] = [
+    &day01::Day1,
+    &day02::Day2,
+    &day10::Day10,
+    &day30::Day30,
+];
+";
+
+    #[test]
+    fn registers_in_order() {
+        let text = register(MOD_RS, 20).unwrap();
+        assert_eq!(
+            text,
+            "\
+pub mod day01;
+pub mod day02;
+pub mod day10;
+pub mod day20;
+pub mod day30;
+
+use crate::Solver;
+
+/// Every solved day, in order.
+pub static REGISTRY: [&dyn Solver; 5] = [
+    &day01::Day1,
+    &day02::Day2,
+    &day10::Day10,
+    &day20::Day20,
+    &day30::Day30,
+];
+"
+        );
+        assert_eq!(register(&text, 20), None);
+    }
+
+    #[test]
+    fn refuses_to_overwrite() {
+        let dir = scratch_dir("scaffold");
+        let days = dir.join("y2024");
+        let input = dir.join("inputs").join("2024").join("day03.txt");
+        std::fs::create_dir_all(&days).unwrap();
+        std::fs::write(days.join("mod.rs"), MOD_RS).unwrap();
+
+        scaffold(&days, &input, 2024, 3, "Mull It Over").unwrap();
+        let source = std::fs::read_to_string(days.join("day03.rs")).unwrap();
+        assert!(
+            source.starts_with("// Day 3: Mull It Over\n// https://adventofcode.com/2024/day/3\n")
+        );
+        assert_eq!(std::fs::read_to_string(&input).unwrap(), "");
+
+        std::fs::write(days.join("day03.rs"), "// mine").unwrap();
+        assert!(matches!(
+            scaffold(&days, &input, 2024, 3, "Mull It Over"),
+            Err(ScaffoldError::Exists(_))
+        ));
+        assert_eq!(
+            std::fs::read_to_string(days.join("day03.rs")).unwrap(),
+            "// mine"
+        );
+
+        assert!(matches!(
+            scaffold(&dir.join("y2025"), &input, 2025, 1, "TODO"),
+            Err(ScaffoldError::NoYear(2025))
+        ));
+    }
+}
@@ -0,0 +1,308 @@
+// Posting answers to the puzzle site and reading its verdict. Whatever the
+// site says is folded into the day's recorded answers, so a rejected answer
+// is never sent twice and answers outside a known bound are never sent.
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::answers::Answers;
+use crate::fetch::{self, FetchError, Site};
+use crate::http::{Http, Request};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, with no hint as to which way.
+    Wrong,
+    /// Submitted too soon after the previous answer; nothing was checked.
+    Throttled,
+    /// The part is already solved, or part one is not.
+    WrongLevel,
+    /// A page we do not recognise.
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "wrong: too high",
+            Verdict::TooLow => "wrong: too low",
+            Verdict::Wrong => "wrong",
+            Verdict::Throttled => "answered too recently",
+            Verdict::WrongLevel => "not the current level; is it already solved?",
+            Verdict::Unknown => "unrecognised response",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reply {
+    pub verdict: Verdict,
+    /// How long the site wants us to wait before the next submission.
+    pub wait: Option<Duration>,
+}
+
+impl Reply {
+    /// Read the verdict out of the page the site returns.
+    pub fn parse(page: &str) -> Self {
+        let verdict = if page.contains("That's the right answer") {
+            Verdict::Correct
+        } else if page.contains("You gave an answer too recently") {
+            Verdict::Throttled
+        } else if page.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if page.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else {
+            Verdict::Unknown
+        };
+
+        Reply {
+            verdict,
+            wait: parse_wait(page),
+        }
+    }
+}
+
+/// The wait in "You have 1m 5s left to wait" or "please wait 5 minutes
+/// before trying again".
+fn parse_wait(page: &str) -> Option<Duration> {
+    if let Some(end) = page.find(" left to wait") {
+        let start = page[..end].rfind("You have ")? + "You have ".len();
+        let mut secs = 0;
+        for part in page[start..end].split_whitespace() {
+            let (n, unit) = part.split_at(part.len() - 1);
+            let n: u64 = n.parse().ok()?;
+            secs += n * match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+        }
+        return Some(Duration::from_secs(secs));
+    }
+
+    let start = page.find("wait ")? + "wait ".len();
+    let mut words = page[start..].split_whitespace();
+    let minutes = match words.next()? {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+    words
+        .next()?
+        .starts_with("minute")
+        .then(|| Duration::from_secs(60 * minutes))
+}
+
+/// Why an answer was not sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect,
+    AlreadyRejected,
+    /// At or below an answer that was too low.
+    TooLow(i64),
+    /// At or above an answer that was too high.
+    TooHigh(i64),
+    /// The site asked us to wait this many more seconds.
+    Wait(u64),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect => write!(f, "this answer is already recorded as correct"),
+            Refusal::AlreadyRejected => write!(f, "this answer was already rejected"),
+            Refusal::TooLow(bound) => write!(f, "{bound} was already too low"),
+            Refusal::TooHigh(bound) => write!(f, "{bound} was already too high"),
+            Refusal::Wait(secs) => write!(f, "the site asked to wait another {secs}s"),
+        }
+    }
+}
+
+/// Check `answer` against what is already known, `now` being Unix seconds.
+pub fn check(answers: &Answers, part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+    if answers.part(part) == Some(answer) {
+        return Err(Refusal::AlreadyCorrect);
+    }
+
+    let attempts = answers.attempts(part);
+    if attempts.rejected.iter().any(|rejected| rejected == answer) {
+        return Err(Refusal::AlreadyRejected);
+    }
+    if let Ok(value) = answer.parse::<i64>() {
+        match (attempts.too_low, attempts.too_high) {
+            (Some(low), _) if value <= low => return Err(Refusal::TooLow(low)),
+            (_, Some(high)) if value >= high => return Err(Refusal::TooHigh(high)),
+            _ => {}
+        }
+    }
+
+    match answers.retry_after {
+        Some(retry_after) if retry_after > now => Err(Refusal::Wait(retry_after - now)),
+        _ => Ok(()),
+    }
+}
+
+/// Fold the site's reply to `answer` into `answers`.
+pub fn record(answers: &mut Answers, part: u8, answer: &str, reply: &Reply, now: u64) {
+    answers.retry_after = reply.wait.map(|wait| now + wait.as_secs());
+
+    if reply.verdict == Verdict::Correct {
+        answers.set_part(part, answer.to_string());
+        return;
+    }
+    if !matches!(
+        reply.verdict,
+        Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong
+    ) {
+        return;
+    }
+
+    let attempts = answers.attempts_mut(part);
+    attempts.rejected.push(answer.to_string());
+    if let Ok(value) = answer.parse::<i64>() {
+        match reply.verdict {
+            Verdict::TooLow => attempts.too_low = attempts.too_low.max(Some(value)),
+            Verdict::TooHigh => {
+                attempts.too_high = Some(attempts.too_high.map_or(value, |high| high.min(value)))
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Post `answer` for `part` of `day` of `year`.
+pub fn submit(
+    http: &dyn Http,
+    site: &Site,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Reply, FetchError> {
+    let body = format!("level={part}&answer={}", form_encode(answer));
+    let request = Request::post(format!("{}/answer", site.day_url(year, day)), body);
+    let response = site.send(http, request)?;
+    fetch::check_status(year, day, &response)?;
+    Ok(Reply::parse(&response.body))
+}
+
+fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                char::from(b).to_string()
+            }
+            b' ' => "+".to_string(),
+            b => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::site;
+    use crate::http::tests::serve_once;
+    use crate::http::SystemHttp;
+
+    const WRONG_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  \
+        If you're stuck, make sure you're using the full input data.  Please wait one minute \
+        before trying again. <a href=\"/2024/day/7\">[Return to Day 7]</a></p></article>";
+
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>";
+
+    #[test]
+    fn parses_replies() {
+        let reply = |page| Reply::parse(page);
+        assert_eq!(
+            reply(WRONG_HIGH),
+            Reply {
+                verdict: Verdict::TooHigh,
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            reply(TOO_RECENT),
+            Reply {
+                verdict: Verdict::Throttled,
+                wait: Some(Duration::from_secs(65))
+            }
+        );
+        assert_eq!(
+            reply("<p>That's the right answer!  You are one gold star closer.</p>").verdict,
+            Verdict::Correct
+        );
+        assert_eq!(
+            reply("That's not the right answer; your answer is too low. please wait 5 minutes before trying again."),
+            Reply {
+                verdict: Verdict::TooLow,
+                wait: Some(Duration::from_secs(300))
+            }
+        );
+        assert_eq!(
+            reply("You don't seem to be solving the right level.  Did you already complete it?")
+                .verdict,
+            Verdict::WrongLevel
+        );
+    }
+
+    #[test]
+    fn remembers_rejections_and_bounds() {
+        let mut answers = Answers::default();
+        let wrong = |verdict| Reply {
+            verdict,
+            wait: Some(Duration::from_secs(60)),
+        };
+
+        record(&mut answers, 1, "500", &wrong(Verdict::TooHigh), 1000);
+        record(&mut answers, 1, "100", &wrong(Verdict::TooLow), 1000);
+        record(&mut answers, 1, "3,4", &wrong(Verdict::Wrong), 1000);
+
+        assert_eq!(check(&answers, 1, "300", 1000), Err(Refusal::Wait(60)));
+        assert_eq!(
+            check(&answers, 1, "3,4", 2000),
+            Err(Refusal::AlreadyRejected)
+        );
+        assert_eq!(check(&answers, 1, "600", 2000), Err(Refusal::TooHigh(500)));
+        assert_eq!(check(&answers, 1, "99", 2000), Err(Refusal::TooLow(100)));
+        assert_eq!(check(&answers, 1, "300", 2000), Ok(()));
+        assert_eq!(check(&answers, 2, "600", 2000), Ok(()));
+
+        let correct = Reply {
+            verdict: Verdict::Correct,
+            wait: None,
+        };
+        record(&mut answers, 1, "300", &correct, 2000);
+        assert_eq!(answers.part(1), Some("300"));
+        assert_eq!(answers.retry_after, None);
+        assert_eq!(
+            check(&answers, 1, "300", 2000),
+            Err(Refusal::AlreadyCorrect)
+        );
+    }
+
+    #[test]
+    fn posts_to_the_mock_server() {
+        let (url, server) = serve_once(200, WRONG_HIGH);
+        let reply = submit(&SystemHttp, &site(&url), 2024, 7, 2, "4,6 3").unwrap();
+        assert_eq!(reply.verdict, Verdict::TooHigh);
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2024/day/7/answer HTTP/1.0\r\n"));
+        assert!(request.contains("\r\nCookie: session=abc123\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=4%2C6+3"));
+    }
+}
@@ -0,0 +1,11 @@
+// Helpers shared by the tests of several modules.
+
+use std::path::PathBuf;
+
+/// A fresh scratch directory for one test: its path, with anything an
+/// earlier run left there removed. The directory itself is not created.
+pub(crate) fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-{}-{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
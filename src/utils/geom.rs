@@ -0,0 +1,246 @@
+// Points and directions on a grid. Like `Grid`, everything is `(row, col)`
+// with rows growing downwards, so `Up` is a step to the previous row.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub row: i32,
+    pub col: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(row: i32, col: i32) -> Self {
+        Point { row, col }
+    }
+
+    /// The neighbouring point in `dir`.
+    pub fn step(self, dir: Direction) -> Self {
+        self + dir.offset()
+    }
+
+    /// Up, right, down and left neighbours, in [`Direction::ALL`] order.
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |dir| self.step(dir))
+    }
+
+    pub fn manhattan(self, other: Point) -> u32 {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// `(row, col)` as indices, if neither is negative.
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        Some((self.row.try_into().ok()?, self.col.try_into().ok()?))
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((row, col): (i32, i32)) -> Self {
+        Point::new(row, col)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Self {
+        Point::new(row as i32, col as i32)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, k: i32) -> Point {
+        Point::new(self.row * k, self.col * k)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up, the same order as [`NEIGHBORS_4`](super::grid::NEIGHBORS_4).
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(-1, 0),
+            Direction::Right => Point::new(0, 1),
+            Direction::Down => Point::new(1, 0),
+            Direction::Left => Point::new(0, -1),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Read one of `^`, `>`, `v` or `<`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+/// An inclusive rectangle of points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    /// The cells of a `height` by `width` grid.
+    pub fn new(height: usize, width: usize) -> Self {
+        Bounds {
+            min: Point::ORIGIN,
+            max: Point::new(height as i32 - 1, width as i32 - 1),
+        }
+    }
+
+    /// The smallest rectangle holding every point; `None` if there are none.
+    pub fn of(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        points.into_iter().fold(None, |bounds, p| {
+            Some(match bounds {
+                None => Bounds { min: p, max: p },
+                Some(Bounds { min, max }) => Bounds {
+                    min: Point::new(min.row.min(p.row), min.col.min(p.col)),
+                    max: Point::new(max.row.max(p.row), max.col.max(p.col)),
+                },
+            })
+        })
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (self.min.row..=self.max.row).contains(&p.row)
+            && (self.min.col..=self.max.col).contains(&p.col)
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.row - self.min.row + 1).max(0) as usize
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.col - self.min.col + 1).max(0) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_and_arrows() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.offset() + dir.opposite().offset(), Point::ORIGIN);
+            assert_eq!(Direction::from_arrow(dir.arrow()), Some(dir));
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::from_arrow('x'), None);
+    }
+
+    #[test]
+    fn arithmetic_and_distance() {
+        let p = Point::new(2, 3);
+        assert_eq!(p + Point::new(1, -1), Point::new(3, 2));
+        assert_eq!(p - Point::new(2, 3), Point::ORIGIN);
+        assert_eq!(-p * 2, Point::new(-4, -6));
+        assert_eq!(p.step(Direction::Up), Point::new(1, 3));
+        assert_eq!(p.manhattan(Point::new(-1, 5)), 5);
+        assert_eq!(Point::new(0, -1).to_index(), None);
+        assert_eq!(p.to_index(), Some((2, 3)));
+    }
+
+    #[test]
+    fn bounds() {
+        let grid = Bounds::new(2, 3);
+        assert!(grid.contains(Point::new(1, 2)));
+        assert!(!grid.contains(Point::new(2, 0)));
+        assert!(!Bounds::new(0, 0).contains(Point::ORIGIN));
+
+        let points = [Point::new(4, -1), Point::new(-2, 3), Point::new(0, 0)];
+        let bounds = Bounds::of(points).unwrap();
+        assert_eq!(bounds.min, Point::new(-2, -1));
+        assert_eq!((bounds.height(), bounds.width()), (7, 5));
+        assert_eq!(Bounds::of([]), None);
+    }
+}
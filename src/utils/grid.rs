@@ -0,0 +1,333 @@
+// A rectangular grid stored as one flat row-major buffer. Positions are
+// `(row, col)`; lookups that may fall off the edge take signed coordinates.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::ParseError;
+use crate::utils::geom::{Bounds, Direction, Point};
+use crate::utils::memo::Store;
+use crate::utils::parse;
+
+/// Offsets to the up, right, down and left neighbours.
+pub const NEIGHBORS_4: [(i32, i32); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets to all eight surrounding cells, clockwise from up.
+pub const NEIGHBORS_8: [(i32, i32); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Build a grid from row-major cells.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "cell count does not match size"
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parse one row per line, mapping each character through `f`. Blank
+    /// lines at either end and trailing whitespace are ignored; rows of
+    /// different widths are an error. See [`parse::grid`] to reject
+    /// unexpected characters.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        parse::grid(input, "a grid cell", |c| Some(f(c)))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, row: i32, col: i32) -> bool {
+        row >= 0 && col >= 0 && (row as usize) < self.height && (col as usize) < self.width
+    }
+
+    pub fn get(&self, row: i32, col: i32) -> Option<&T> {
+        self.contains(row, col)
+            .then(|| &self.cells[row as usize * self.width + col as usize])
+    }
+
+    pub fn get_mut(&mut self, row: i32, col: i32) -> Option<&mut T> {
+        if self.contains(row, col) {
+            Some(&mut self.cells[row as usize * self.width + col as usize])
+        } else {
+            None
+        }
+    }
+
+    /// The cell at `p`, if it is inside the grid.
+    pub fn at(&self, p: Point) -> Option<&T> {
+        self.get(p.row, p.col)
+    }
+
+    pub fn at_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.get_mut(p.row, p.col)
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        Bounds::new(self.height, self.width)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every down-right diagonal, starting along the top row then down the
+    /// left column.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.width)
+            .map(|col| (0, col))
+            .chain((1..self.height).map(|row| (row, 0)));
+        starts.map(move |(row, col)| self.walk(row, col, 1))
+    }
+
+    /// Every down-left diagonal, starting along the top row then down the
+    /// right column.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let last_col = self.width.saturating_sub(1);
+        let starts = (0..self.width)
+            .map(|col| (0, col))
+            .chain((1..self.height).map(move |row| (row, last_col)));
+        starts.map(move |(row, col)| self.walk(row, col, -1))
+    }
+
+    /// Cells from `(row, col)` stepping one row down and `dc` columns across.
+    fn walk(&self, row: usize, col: usize, dc: i32) -> impl Iterator<Item = &T> {
+        (0..)
+            .map(move |i| (row as i32 + i, col as i32 + dc * i))
+            .map_while(move |(r, c)| self.get(r, c))
+    }
+
+    /// In-bounds neighbours of `(row, col)` for each of the given offsets.
+    pub fn neighbors<'a>(
+        &'a self,
+        (row, col): (usize, usize),
+        offsets: &'a [(i32, i32)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |&(dr, dc)| {
+            let (r, c) = (row as i32 + dr, col as i32 + dc);
+            self.contains(r, c).then_some((r as usize, c as usize))
+        })
+    }
+
+    /// In-bounds up, right, down and left neighbours.
+    pub fn neighbors4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(pos, &NEIGHBORS_4)
+    }
+
+    /// In-bounds neighbours including diagonals.
+    pub fn neighbors8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(pos, &NEIGHBORS_8)
+    }
+
+    /// Every position with its cell, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / width, i % width), cell))
+    }
+
+    /// Position of the first cell equal to `value`.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(col < self.width, "column {col} out of bounds");
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(col < self.width, "column {col} out of bounds");
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.at(p)
+            .unwrap_or_else(|| panic!("{p} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.at_mut(p)
+            .unwrap_or_else(|| panic!("{p} is outside the grid"))
+    }
+}
+
+/// A dense store for values per cell, e.g. search costs.
+impl<V> Store<Point, V> for Grid<Option<V>> {
+    fn lookup(&self, &p: &Point) -> Option<&V> {
+        self.at(p)?.as_ref()
+    }
+
+    fn store(&mut self, p: Point, value: V) {
+        self[p] = Some(value);
+    }
+
+    fn len(&self) -> usize {
+        self.cells.iter().flatten().count()
+    }
+}
+
+/// A dense store for values per cell and facing.
+impl<V> Store<(Point, Direction), V> for Grid<[Option<V>; 4]> {
+    fn lookup(&self, &(p, dir): &(Point, Direction)) -> Option<&V> {
+        self.at(p)?[dir as usize].as_ref()
+    }
+
+    fn store(&mut self, (p, dir): (Point, Direction), value: V) {
+        self[p][dir as usize] = Some(value);
+    }
+
+    fn len(&self) -> usize {
+        self.cells.iter().flatten().flatten().count()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "abc\ndef\r\n";
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn parse_and_index() {
+        let grid = Grid::parse(SAMPLE, |c| c).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.get(0, 1), Some(&'b'));
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.find(&'e'), Some((1, 1)));
+        assert_eq!(grid[Point::new(1, 0)], 'd');
+        assert_eq!(grid.at(Point::new(2, 0)), None);
+        assert!(grid.bounds().contains(Point::new(1, 2)));
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn lines_through_the_grid() {
+        let grid = Grid::parse(SAMPLE, |c| c).unwrap();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(collect(grid.columns()), ["ad", "be", "cf"]);
+        assert_eq!(collect(grid.diagonals()), ["ae", "bf", "c", "d"]);
+        assert_eq!(collect(grid.anti_diagonals()), ["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = Grid::new(3, 2, 0);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn stores_values_per_cell() {
+        let mut costs = Grid::new(3, 2, [None; 4]);
+        let node = (Point::new(1, 2), Direction::Left);
+        costs.store(node, 7);
+        assert_eq!(costs.lookup(&node), Some(&7));
+        assert_eq!(costs.lookup(&(Point::new(1, 2), Direction::Up)), None);
+        assert_eq!(costs.lookup(&(Point::new(2, 0), Direction::Left)), None);
+        assert_eq!(Store::len(&costs), 1);
+    }
+
+    #[test]
+    fn parse_rejects_ragged_rows() {
+        let err = Grid::parse("ab\nc\n", |c| c).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.expected, "a row of width 2");
+    }
+}
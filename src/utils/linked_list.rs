@@ -21,7 +21,7 @@ impl ListNode {
             current = &mut current.as_mut().unwrap().next;
         }
 
-        return head;
+        head
     }
 
     pub fn to_array(head: Option<Box<ListNode>>) -> Vec<i32> {
@@ -33,6 +33,6 @@ impl ListNode {
             result.push(curr_content.val);
             curr = curr_content.next;
         }
-        return result;
+        result
     }
 }
@@ -0,0 +1,196 @@
+// A cache for recursive solvers. The value for a key is computed by a
+// closure that gets the cache back, so it can recurse through it:
+//
+//     fn fib(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+//         memo.get(n, |memo, &n| match n {
+//             0 | 1 => n,
+//             _ => fib(n - 1, memo) + fib(n - 2, memo),
+//         })
+//     }
+//
+// Hits and misses are counted so a solver can check the cache pays off.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// Somewhere to keep computed values.
+pub trait Store<K, V> {
+    fn lookup(&self, key: &K) -> Option<&V>;
+    fn store(&mut self, key: K, value: V);
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: Hash + Eq, V> Store<K, V> for HashMap<K, V> {
+    fn lookup(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn store(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+}
+
+/// A dense store for small `usize` keys, growing as needed.
+impl<V> Store<usize, V> for Vec<Option<V>> {
+    fn lookup(&self, &key: &usize) -> Option<&V> {
+        self.get(key)?.as_ref()
+    }
+
+    fn store(&mut self, key: usize, value: V) {
+        if key >= self.len() {
+            self.resize_with(key + 1, || None);
+        }
+        self[key] = Some(value);
+    }
+
+    fn len(&self) -> usize {
+        self.iter().flatten().count()
+    }
+}
+
+/// How often a [`Memo`] already had the value asked for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl MemoStats {
+    /// The fraction of lookups that were hits, or 0 before any lookup.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            total => self.hits as f64 / total as f64,
+        }
+    }
+}
+
+impl fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate)",
+            self.hits,
+            self.misses,
+            100.0 * self.hit_rate()
+        )
+    }
+}
+
+/// Values computed once per key, kept in a `HashMap` unless another
+/// [`Store`] is given.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V, S = HashMap<K, V>> {
+    store: S,
+    stats: MemoStats,
+    _entry: std::marker::PhantomData<(K, V)>,
+}
+
+impl<K: Hash + Eq, V> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo::with_store(HashMap::new())
+    }
+}
+
+impl<K: Hash + Eq, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo::new()
+    }
+}
+
+impl<V> Memo<usize, V, Vec<Option<V>>> {
+    /// A memo for keys below `capacity` (more is allowed, but regrows).
+    pub fn dense(capacity: usize) -> Self {
+        Memo::with_store((0..capacity).map(|_| None).collect())
+    }
+}
+
+impl<K, V, S> Memo<K, V, S> {
+    pub fn with_store(store: S) -> Self {
+        Memo {
+            store,
+            stats: MemoStats::default(),
+            _entry: std::marker::PhantomData,
+        }
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+}
+
+impl<K, V: Clone, S: Store<K, V>> Memo<K, V, S> {
+    /// The value for `key`, computed by `compute` if it is not cached yet.
+    /// `compute` gets the memo back for looking up other keys; a key it
+    /// looks up while computing that same key is computed again.
+    pub fn get(&mut self, key: K, compute: impl FnOnce(&mut Self, &K) -> V) -> V {
+        if let Some(value) = self.store.lookup(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        let value = compute(self, &key);
+        self.store.store(key, value.clone());
+        value
+    }
+
+    /// How many values are cached.
+    pub fn len(&self) -> usize {
+        self.store.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.store.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fib(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        memo.get(n, |memo, &n| match n {
+            0 | 1 => n,
+            _ => fib(n - 1, memo) + fib(n - 2, memo),
+        })
+    }
+
+    #[test]
+    fn recursion_hits_the_cache() {
+        let mut memo = Memo::new();
+        assert_eq!(fib(90, &mut memo), 2_880_067_194_370_816_120);
+        // Each of 0..=90 is computed once, and fib(n - 2) is a hit for n > 2.
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                hits: 88,
+                misses: 91
+            }
+        );
+        assert_eq!(memo.len(), 91);
+
+        fib(90, &mut memo);
+        assert_eq!(memo.stats().hits, 89);
+    }
+
+    #[test]
+    fn dense_store_grows_past_its_capacity() {
+        let mut memo = Memo::dense(2);
+        assert_eq!(memo.get(5, |_, &k| k * 10), 50);
+        assert_eq!(memo.get(5, |_, _| unreachable!()), 50);
+        assert_eq!(memo.len(), 1);
+        assert_eq!(
+            memo.stats().to_string(),
+            "1 hits, 1 misses (50.0% hit rate)"
+        );
+    }
+}
@@ -1,2 +1,8 @@
 pub mod binary_tree;
+pub mod geom;
+pub mod grid;
 pub mod linked_list;
+pub mod memo;
+pub mod parse;
+pub mod pathfinding;
+pub mod rng;
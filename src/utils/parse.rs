@@ -0,0 +1,241 @@
+// Helpers for pulling values out of puzzle input. Everything works on
+// numbered `Line`s, so a bad token is reported with its line and column.
+
+use std::str::FromStr;
+
+use crate::error::ParseError;
+pub use crate::error::{lines, sections, Line};
+use crate::utils::grid::Grid;
+
+/// Every integer in `line`, in order. An integer is a run of digits, negative
+/// when a `-` comes right before it; anything between integers is skipped, so
+/// `p=0,4 v=3,-3` gives `[0, 4, 3, -3]`. A word mixing digits and letters,
+/// like `3x`, is an error rather than a 3.
+pub fn ints<T: FromStr>(line: Line<'_>) -> Result<Vec<T>, ParseError> {
+    numbers(line, true)
+}
+
+/// Like [`ints`], but a `-` separates integers rather than negating them, so
+/// `3-7` gives `[3, 7]`.
+pub fn uints<T: FromStr>(line: Line<'_>) -> Result<Vec<T>, ParseError> {
+    numbers(line, false)
+}
+
+/// Exactly `N` integers, found as by [`ints`].
+pub fn int_array<T: FromStr, const N: usize>(line: Line<'_>) -> Result<[T; N], ParseError> {
+    array(line, true)
+}
+
+/// Exactly `N` integers, found as by [`uints`].
+pub fn uint_array<T: FromStr, const N: usize>(line: Line<'_>) -> Result<[T; N], ParseError> {
+    array(line, false)
+}
+
+fn numbers<T: FromStr>(line: Line<'_>, signed: bool) -> Result<Vec<T>, ParseError> {
+    number_tokens(line, signed)?
+        .into_iter()
+        .map(|token| line.parse(token, "integer"))
+        .collect()
+}
+
+fn array<T: FromStr, const N: usize>(line: Line<'_>, signed: bool) -> Result<[T; N], ParseError> {
+    let tokens = number_tokens(line, signed)?;
+    if let Some(extra) = tokens.get(N) {
+        return Err(line.error(extra, format!("only {N} integers")));
+    }
+    if tokens.len() < N {
+        return Err(line.error(&line.text[line.text.len()..], format!("{N} integers")));
+    }
+
+    let values = tokens
+        .into_iter()
+        .map(|token| line.parse(token, "integer"))
+        .collect::<Result<Vec<T>, _>>()?;
+    match values.try_into() {
+        Ok(array) => Ok(array),
+        Err(_) => unreachable!("checked there are {N} integers"),
+    }
+}
+
+/// The integers of `line` as slices of it, checked for stray letters but not
+/// yet parsed.
+fn number_tokens(line: Line<'_>, signed: bool) -> Result<Vec<&str>, ParseError> {
+    let text = line.text;
+    let mut tokens = Vec::new();
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if !is_word(c) {
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        while let Some(&(i, c)) = chars.peek().filter(|&&(_, c)| is_word(c)) {
+            end = i + c.len_utf8();
+            chars.next();
+        }
+
+        let word = &text[start..end];
+        if word.bytes().all(|b| b.is_ascii_digit()) {
+            let negative = signed && text[..start].ends_with('-');
+            tokens.push(&text[start - usize::from(negative)..end]);
+        } else if word.chars().any(|c| c.is_ascii_digit()) {
+            return Err(line.error(word, "integer"));
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Split a `key: value` line around its first colon, trimming both sides.
+pub fn key_value<'a>(line: Line<'a>) -> Result<(&'a str, &'a str), ParseError> {
+    let (key, value) = line.split_once(line.text, ":")?;
+    Ok((key.trim(), value.trim()))
+}
+
+/// The value of a `key: value` line, which must have the given `key`.
+pub fn field<'a>(line: Line<'a>, key: &str) -> Result<&'a str, ParseError> {
+    match line.text.split_once(':') {
+        Some((found, value)) if found.trim() == key => Ok(value.trim()),
+        _ => Err(line.error(line.text, format!("`{key}:`"))),
+    }
+}
+
+/// Parse one grid row per line, mapping each character through `cell`. A
+/// character it rejects is reported as not being `expected`. Blank lines at
+/// either end and trailing whitespace are ignored; rows of different widths
+/// are an error.
+pub fn grid<T>(
+    input: &str,
+    expected: &str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<Grid<T>, ParseError> {
+    let mut width = 0;
+    let mut height = 0;
+    let mut cells = Vec::with_capacity(input.len());
+
+    let mut rows: Vec<_> = lines(input).collect();
+    while rows.last().is_some_and(|line| line.text.trim().is_empty()) {
+        rows.pop();
+    }
+
+    for line in rows
+        .into_iter()
+        .skip_while(|line| line.text.trim().is_empty())
+    {
+        let text = line.text.trim_end();
+        let before = cells.len();
+        for (i, c) in text.char_indices() {
+            match cell(c) {
+                Some(value) => cells.push(value),
+                None => return Err(line.error(&text[i..i + c.len_utf8()], expected)),
+            }
+        }
+        let row_width = cells.len() - before;
+
+        if height == 0 {
+            width = row_width;
+        }
+        if row_width != width {
+            let at = text
+                .char_indices()
+                .nth(width)
+                .map_or(text.len(), |(i, _)| i);
+            return Err(line.error(&text[at..], format!("a row of width {width}")));
+        }
+        height += 1;
+    }
+
+    Ok(Grid::from_vec(width, height, cells))
+}
+
+/// Where `cell` first appears in `grid`, which was parsed from `input`. A grid
+/// without one is an error at the end of `input`, expecting `expected`.
+pub fn find_cell<T: PartialEq>(
+    grid: &Grid<T>,
+    cell: &T,
+    input: &str,
+    expected: &str,
+) -> Result<(usize, usize), ParseError> {
+    grid.find(cell)
+        .ok_or_else(|| ParseError::end_of_input(lines(input).count() + 1, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str) -> Line<'_> {
+        Line { number: 4, text }
+    }
+
+    #[test]
+    fn finds_integers_between_other_text() {
+        assert_eq!(ints::<i32>(line("p=0,4 v=3,-3")).unwrap(), [0, 4, 3, -3]);
+        assert_eq!(uints::<u32>(line("3-7, 12")).unwrap(), [3, 7, 12]);
+        assert_eq!(ints::<i64>(line("Button A: X+94, Y+34")).unwrap(), [94, 34]);
+        assert!(ints::<u8>(line("no numbers here")).unwrap().is_empty());
+
+        let err = ints::<i32>(line("X+94, Y+3x")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (4, 9, "3x"));
+        let err = uints::<u8>(line("1, 300")).unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (4, "300"));
+    }
+
+    #[test]
+    fn arrays_need_exactly_n_integers() {
+        assert_eq!(int_array::<i32, 2>(line("5,-4")).unwrap(), [5, -4]);
+        assert_eq!(
+            uint_array::<usize, 2>(line("5")).unwrap_err().to_string(),
+            "line 4, column 2: expected 2 integers, found end of line"
+        );
+        assert_eq!(
+            uint_array::<usize, 2>(line("5,4,3"))
+                .unwrap_err()
+                .to_string(),
+            "line 4, column 5: expected only 2 integers, found `3`"
+        );
+    }
+
+    #[test]
+    fn reads_key_value_lines() {
+        assert_eq!(key_value(line("190: 10 19")).unwrap(), ("190", "10 19"));
+        assert_eq!(field(line("Register A: 729"), "Register A").unwrap(), "729");
+        assert_eq!(
+            field(line("Register B: 1"), "Register A")
+                .unwrap_err()
+                .to_string(),
+            "line 4, column 1: expected `Register A:`, found `Register B: 1`"
+        );
+    }
+
+    #[test]
+    fn grid_rejects_unexpected_cells() {
+        let walls = |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+        let grid = grid("\n#.\n.#\n\n", "`#` or `.`", walls).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert!(grid[(1, 1)]);
+
+        let err = super::grid("#.\n.x\n", "`#` or `.`", walls).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected `#` or `.`, found `x`"
+        );
+
+        assert_eq!(
+            find_cell(&grid, &true, "\n#.\n.#\n\n", "a wall"),
+            Ok((0, 0))
+        );
+        let open = super::grid("..\n..\n", "`#` or `.`", walls).unwrap();
+        assert_eq!(
+            find_cell(&open, &true, "..\n..\n", "a wall")
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 1: expected a wall, found end of line"
+        );
+    }
+}
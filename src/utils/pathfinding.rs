@@ -0,0 +1,291 @@
+// Shortest-path searches over an implicit graph described by a successor
+// function. Nodes can be anything hashable, e.g. `(row, col)` or
+// `(row, col, facing)`. Costs are kept in a `HashMap` unless another
+// [`Store`] is given, such as a grid for searches over its cells.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::utils::memo::Store;
+
+/// The result of a search: the cost of every node reached and, for each, all
+/// predecessors that lie on a cheapest path to it.
+#[derive(Debug, Clone)]
+pub struct Paths<N, S = HashMap<N, u64>> {
+    pub dist: S,
+    /// Empty for a search that keeps [costs only](Paths::costs_only).
+    pub prev: HashMap<N, Vec<N>>,
+    /// The goal node the search stopped at, if one was reached.
+    pub goal: Option<N>,
+    keep_prev: bool,
+}
+
+impl<N: Eq + Hash + Clone> Paths<N> {
+    pub fn new() -> Self {
+        Paths::with_store(HashMap::new())
+    }
+}
+
+impl<N: Eq + Hash + Clone> Default for Paths<N> {
+    fn default() -> Self {
+        Paths::new()
+    }
+}
+
+impl<N: Eq + Hash + Clone, S: Store<N, u64>> Paths<N, S> {
+    /// An empty search keeping its costs in `dist`.
+    pub fn with_store(dist: S) -> Self {
+        Paths {
+            dist,
+            prev: HashMap::new(),
+            goal: None,
+            keep_prev: true,
+        }
+    }
+
+    /// Keep only costs, not predecessors, for searches that need no path.
+    pub fn costs_only(mut self) -> Self {
+        self.keep_prev = false;
+        self
+    }
+
+    pub fn cost(&self, node: &N) -> Option<u64> {
+        self.dist.lookup(node).copied()
+    }
+
+    /// Cost of the goal node, if one was reached.
+    pub fn goal_cost(&self) -> Option<u64> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    /// One cheapest path from a start node to `node`, inclusive of both ends.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.cost(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(prev) = self.prev.get(path.last()?).and_then(|p| p.first()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Record `next` as reached at `cost` via `from`. Returns whether `next`
+    /// got cheaper and so needs (re)visiting.
+    fn relax(&mut self, from: &N, next: N, cost: u64) -> bool {
+        match self.cost(&next) {
+            Some(old) if cost > old => false,
+            Some(old) if cost == old => {
+                if self.keep_prev {
+                    self.prev.entry(next).or_default().push(from.clone());
+                }
+                false
+            }
+            _ => {
+                if self.keep_prev {
+                    self.prev.insert(next.clone(), vec![from.clone()]);
+                }
+                self.dist.store(next, cost);
+                true
+            }
+        }
+    }
+
+    /// Dijkstra's algorithm from `starts`, stopping once a node satisfying
+    /// `is_goal` is settled. Edge costs must be non-negative.
+    pub fn dijkstra<I>(
+        self,
+        starts: impl IntoIterator<Item = N>,
+        successors: impl FnMut(&N) -> I,
+        is_goal: impl FnMut(&N) -> bool,
+    ) -> Self
+    where
+        I: IntoIterator<Item = (N, u64)>,
+    {
+        self.astar(starts, successors, |_| 0, is_goal)
+    }
+
+    /// A* search from `starts`. `heuristic` must never overestimate the
+    /// remaining cost to a goal, or the path found may not be the cheapest.
+    pub fn astar<I>(
+        mut self,
+        starts: impl IntoIterator<Item = N>,
+        mut successors: impl FnMut(&N) -> I,
+        mut heuristic: impl FnMut(&N) -> u64,
+        mut is_goal: impl FnMut(&N) -> bool,
+    ) -> Self
+    where
+        I: IntoIterator<Item = (N, u64)>,
+    {
+        // The heap holds indices into `nodes`, which pairs each node with the
+        // cost it was queued at, so that `N` need not be `Ord`.
+        let mut nodes = Vec::new();
+        let mut heap = BinaryHeap::new();
+
+        for start in starts {
+            self.dist.store(start.clone(), 0);
+            heap.push(Reverse((heuristic(&start), nodes.len())));
+            nodes.push((start, 0));
+        }
+
+        while let Some(Reverse((_, index))) = heap.pop() {
+            let (node, cost) = nodes[index].clone();
+            if self.cost(&node).is_some_and(|best| cost > best) {
+                continue;
+            }
+            if is_goal(&node) {
+                self.goal = Some(node);
+                break;
+            }
+
+            for (next, step) in successors(&node) {
+                let next_cost = cost + step;
+                if self.relax(&node, next.clone(), next_cost) {
+                    heap.push(Reverse((next_cost + heuristic(&next), nodes.len())));
+                    nodes.push((next, next_cost));
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Breadth-first search from `starts` where every step costs 1, stopping
+    /// once a node satisfying `is_goal` is reached.
+    pub fn bfs<I>(
+        mut self,
+        starts: impl IntoIterator<Item = N>,
+        mut successors: impl FnMut(&N) -> I,
+        mut is_goal: impl FnMut(&N) -> bool,
+    ) -> Self
+    where
+        I: IntoIterator<Item = N>,
+    {
+        let mut queue = VecDeque::new();
+
+        for start in starts {
+            self.dist.store(start.clone(), 0);
+            queue.push_back((start, 0));
+        }
+
+        while let Some((node, cost)) = queue.pop_front() {
+            if is_goal(&node) {
+                self.goal = Some(node);
+                break;
+            }
+
+            for next in successors(&node) {
+                if self.relax(&node, next.clone(), cost + 1) {
+                    queue.push_back((next, cost + 1));
+                }
+            }
+        }
+
+        self
+    }
+}
+
+/// [`Paths::dijkstra`] with costs and predecessors in `HashMap`s.
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    Paths::new().dijkstra(starts, successors, is_goal)
+}
+
+/// [`Paths::astar`] with costs and predecessors in `HashMap`s.
+pub fn astar<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> u64,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    Paths::new().astar(starts, successors, heuristic, is_goal)
+}
+
+/// [`Paths::bfs`] with costs and predecessors in `HashMap`s.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    Paths::new().bfs(starts, successors, is_goal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A line of nodes 0..=9 with a costly shortcut from 0 straight to 9.
+    fn line(n: &u32) -> Vec<(u32, u64)> {
+        let mut next = Vec::new();
+        if *n < 9 {
+            next.push((n + 1, 1));
+        }
+        if *n == 0 {
+            next.push((9, 20));
+        }
+        next
+    }
+
+    #[test]
+    fn dijkstra_finds_cheapest_path() {
+        let paths = dijkstra([0], line, |&n| n == 9);
+        assert_eq!(paths.goal, Some(9));
+        assert_eq!(paths.goal_cost(), Some(9));
+        assert_eq!(paths.path_to(&9), Some((0..=9).collect()));
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let paths = astar([0], line, |&n| u64::from(9 - n), |&n| n == 9);
+        assert_eq!(paths.goal_cost(), Some(9));
+        assert_eq!(paths.path_to(&9).map(|p| p.len()), Some(10));
+    }
+
+    #[test]
+    fn bfs_keeps_every_shortest_predecessor() {
+        // A diamond: 0 -> {1, 2} -> 3.
+        let successors = |n: &u32| match n {
+            0 => vec![1, 2],
+            1 | 2 => vec![3],
+            _ => vec![],
+        };
+        let paths = bfs([0], successors, |&n| n == 3);
+        assert_eq!(paths.cost(&3), Some(2));
+        assert_eq!(paths.prev[&3], [1, 2]);
+        assert_eq!(paths.path_to(&3), Some(vec![0, 1, 3]));
+    }
+
+    #[test]
+    fn dense_costs_only_search() {
+        // Nodes 0..10 in a line, kept in a `Vec` rather than a `HashMap`.
+        let successors = |&n: &usize| (n < 9).then_some(n + 1);
+        let paths = Paths::with_store(Vec::new())
+            .costs_only()
+            .bfs([0], successors, |&n| n == 9);
+        assert_eq!(paths.goal_cost(), Some(9));
+        assert_eq!(paths.dist.len(), 10);
+        assert!(paths.prev.is_empty());
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let paths = bfs([0u32], |_| Vec::new(), |&n| n == 1);
+        assert_eq!(paths.goal, None);
+        assert_eq!(paths.path_to(&1), None);
+    }
+}
@@ -0,0 +1,385 @@
+// Terminal animations of the simulation days. A day turns each step of its
+// simulation into a `Frame` and hands it to a `Screen`: either the `Player`,
+// which draws it with ANSI colours and takes keyboard controls, or a
+// `FrameDump`, which writes it to a text file for when there is no terminal.
+
+use std::fmt::Write as _;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::utils::grid::Grid;
+
+/// Default speed of the [`Player`].
+pub const DEFAULT_FPS: u32 = 30;
+
+/// Fastest the [`Player`] can be sped up to.
+const MAX_FPS: u32 = 960;
+
+/// How often a paused [`Player`] checks for keys.
+const KEY_POLL: Duration = Duration::from_millis(20);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+}
+
+impl Color {
+    /// The SGR code selecting this as the foreground colour.
+    fn code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+            Color::Gray => 90,
+        }
+    }
+}
+
+/// The colour to draw each kind of cell in; `None` leaves the terminal's own.
+pub type Palette = fn(char) -> Option<Color>;
+
+/// One picture of a simulation: a caption over rows of cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub rows: Vec<String>,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, rows: Vec<String>) -> Self {
+        Frame {
+            caption: caption.into(),
+            rows,
+        }
+    }
+
+    /// Draw `grid` with `cell` choosing the character for each position.
+    pub fn from_grid<T>(
+        caption: impl Into<String>,
+        grid: &Grid<T>,
+        mut cell: impl FnMut((usize, usize), &T) -> char,
+    ) -> Self {
+        let rows = (0..grid.height())
+            .map(|row| {
+                grid.row(row)
+                    .iter()
+                    .enumerate()
+                    .map(|(col, value)| cell((row, col), value))
+                    .collect()
+            })
+            .collect();
+        Frame::new(caption, rows)
+    }
+
+    /// The caption and rows as plain text.
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}\n", self.caption);
+        for row in &self.rows {
+            text.push_str(row);
+            text.push('\n');
+        }
+        text
+    }
+
+    /// The rows coloured by `palette`, switching colour only between runs of
+    /// differently coloured cells.
+    pub fn to_ansi(&self, palette: Palette) -> String {
+        let mut text = String::new();
+        for row in &self.rows {
+            let mut current = None;
+            for c in row.chars() {
+                let color = palette(c);
+                if color != current {
+                    match color {
+                        Some(color) => write!(text, "\x1b[{}m", color.code()).unwrap(),
+                        None => text.push_str("\x1b[0m"),
+                    }
+                    current = color;
+                }
+                text.push(c);
+            }
+            if current.is_some() {
+                text.push_str("\x1b[0m");
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// Where a simulation sends its frames.
+pub trait Screen {
+    /// Show the next frame. Returns `false` once the simulation should stop,
+    /// e.g. because the viewer quit.
+    fn show(&mut self, frame: &Frame) -> bool;
+}
+
+/// Keeps every frame; mostly for tests.
+impl Screen for Vec<Frame> {
+    fn show(&mut self, frame: &Frame) -> bool {
+        self.push(frame.clone());
+        true
+    }
+}
+
+/// Writes each frame as plain text to `frame_00000.txt`, `frame_00001.txt`,
+/// ... in a directory.
+#[derive(Debug)]
+pub struct FrameDump {
+    dir: PathBuf,
+    count: usize,
+    error: Option<io::Error>,
+}
+
+impl FrameDump {
+    /// Dump into `dir`, creating it if needed.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(FrameDump {
+            dir,
+            count: 0,
+            error: None,
+        })
+    }
+
+    /// The number of frames written, or the error that stopped the dump.
+    pub fn finish(self) -> io::Result<usize> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.count),
+        }
+    }
+}
+
+impl Screen for FrameDump {
+    fn show(&mut self, frame: &Frame) -> bool {
+        let path = self.dir.join(format!("frame_{:05}.txt", self.count));
+        match std::fs::write(path, frame.to_text()) {
+            Ok(()) => {
+                self.count += 1;
+                true
+            }
+            Err(e) => {
+                self.error = Some(e);
+                false
+            }
+        }
+    }
+}
+
+/// Plays frames in the terminal.
+///
+/// Keys: space pauses and resumes, `n` steps one frame while paused, `+` and
+/// `-` double and halve the speed, and `q` quits. They take effect without
+/// Enter where `stty` can switch the terminal out of line mode.
+pub struct Player {
+    palette: Palette,
+    fps: u32,
+    paused: bool,
+    step: bool,
+    quit: bool,
+    shown: usize,
+    keys: Receiver<u8>,
+    /// `stty` settings to restore when done.
+    saved_tty: Option<String>,
+    error: Option<io::Error>,
+}
+
+impl Player {
+    pub fn new(palette: Palette, fps: u32) -> Self {
+        let saved_tty = stty(&["-g"]).filter(|_| stty(&["-icanon", "-echo"]).is_some());
+
+        let (send, keys) = mpsc::channel();
+        thread::spawn(move || {
+            let mut buf = [0; 16];
+            while let Ok(n @ 1..) = io::stdin().read(&mut buf) {
+                if buf[..n].iter().any(|&key| send.send(key).is_err()) {
+                    break;
+                }
+            }
+        });
+
+        // Clear the screen and hide the cursor.
+        print!("\x1b[2J\x1b[?25l");
+        Player {
+            palette,
+            fps: fps.clamp(1, MAX_FPS),
+            paused: false,
+            step: false,
+            quit: false,
+            shown: 0,
+            keys,
+            saved_tty,
+            error: None,
+        }
+    }
+
+    /// The number of frames shown, or the error that stopped playback.
+    pub fn finish(mut self) -> io::Result<usize> {
+        match self.error.take() {
+            Some(e) => Err(e),
+            None => Ok(self.shown),
+        }
+    }
+
+    fn draw(&mut self, frame: &Frame) {
+        let state = if self.paused { "paused" } else { "playing" };
+        let mut screen = format!("\x1b[H{}\x1b[K\n", frame.caption);
+        screen.push_str(&frame.to_ansi(self.palette).replace('\n', "\x1b[K\n"));
+        write!(
+            screen,
+            "\x1b[K\n{state} at {} fps | space: pause  n: step  +/-: speed  q: quit\x1b[K\x1b[J",
+            self.fps
+        )
+        .unwrap();
+
+        let mut stdout = io::stdout().lock();
+        if let Err(e) = stdout
+            .write_all(screen.as_bytes())
+            .and_then(|()| stdout.flush())
+        {
+            self.error = Some(e);
+            self.quit = true;
+        }
+    }
+
+    /// Apply the keys pressed so far, returning whether any changed what the
+    /// status line shows.
+    fn read_keys(&mut self) -> bool {
+        let mut changed = false;
+        while let Ok(key) = self.keys.try_recv() {
+            match key {
+                b' ' => self.paused = !self.paused,
+                b'n' | b'.' => {
+                    self.paused = true;
+                    self.step = true;
+                }
+                b'+' | b'=' => self.fps = (self.fps * 2).min(MAX_FPS),
+                b'-' | b'_' => self.fps = (self.fps / 2).max(1),
+                b'q' | 0x1b => self.quit = true,
+                _ => continue,
+            }
+            changed = true;
+        }
+        changed
+    }
+}
+
+impl Screen for Player {
+    fn show(&mut self, frame: &Frame) -> bool {
+        if self.quit {
+            return false;
+        }
+        self.draw(frame);
+        self.shown += 1;
+
+        let deadline = Instant::now() + Duration::from_secs(1) / self.fps;
+        loop {
+            if self.read_keys() {
+                self.draw(frame);
+            }
+            if self.quit {
+                return false;
+            }
+            if self.step {
+                self.step = false;
+                return true;
+            }
+
+            let now = Instant::now();
+            if self.paused {
+                thread::sleep(KEY_POLL);
+            } else if now >= deadline {
+                return true;
+            } else {
+                thread::sleep(KEY_POLL.min(deadline - now));
+            }
+        }
+    }
+}
+
+impl Drop for Player {
+    fn drop(&mut self) {
+        print!("\x1b[0m\x1b[?25h");
+        let _ = io::stdout().flush();
+        if let Some(saved) = &self.saved_tty {
+            stty(&[saved]);
+        }
+    }
+}
+
+/// Run `stty` on our terminal, returning its output if it succeeded.
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::scratch_dir;
+
+    fn palette(c: char) -> Option<Color> {
+        match c {
+            '#' => Some(Color::Gray),
+            'O' => Some(Color::Yellow),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn colours_runs_of_cells() {
+        let frame = Frame::new("t = 0", vec!["##.O".into(), "..".into()]);
+        assert_eq!(
+            frame.to_ansi(palette),
+            "\x1b[90m##\x1b[0m.\x1b[33mO\x1b[0m\n..\n"
+        );
+        assert_eq!(frame.to_text(), "t = 0\n##.O\n..\n");
+    }
+
+    #[test]
+    fn dumps_numbered_frames() {
+        let dir = scratch_dir("frames");
+        let grid = Grid::parse("#.\n.O\n", |c| c).unwrap();
+        let mut dump = FrameDump::new(&dir).unwrap();
+        for step in 0..3 {
+            let frame = Frame::from_grid(format!("step {step}"), &grid, |(row, col), &c| {
+                if (row, col) == (0, 1) {
+                    '@'
+                } else {
+                    c
+                }
+            });
+            assert!(dump.show(&frame));
+        }
+        assert_eq!(dump.finish().unwrap(), 3);
+
+        let last = std::fs::read_to_string(dir.join("frame_00002.txt")).unwrap();
+        assert_eq!(last, "step 2\n#@\n.O\n");
+        assert!(!dir.join("frame_00003.txt").exists());
+    }
+}
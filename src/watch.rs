@@ -0,0 +1,191 @@
+// Support for `aoc watch`: poll a day's files for changes, then rebuild and
+// re-run it through cargo, since a running binary cannot pick up its own
+// source changes.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::SystemTime;
+
+use crate::report::{self, Record, Status};
+use crate::years::Year;
+
+/// Tracks the modification times of a set of files.
+#[derive(Debug)]
+pub struct Watcher {
+    seen: HashMap<PathBuf, Option<SystemTime>>,
+}
+
+impl Watcher {
+    /// Start watching `paths`; the first call to [`changed`](Self::changed)
+    /// reports nothing.
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        let seen = paths
+            .into_iter()
+            .map(|path| {
+                let modified = modified(&path);
+                (path, modified)
+            })
+            .collect();
+        Watcher { seen }
+    }
+
+    /// Files created, modified or removed since the last call.
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        for (path, seen) in &mut self.seen {
+            let now = modified(path);
+            if now != *seen {
+                *seen = now;
+                changed.push(path.clone());
+            }
+        }
+        changed.sort();
+        changed
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// `cargo`, as the one running us if there is one.
+fn cargo() -> Command {
+    Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+}
+
+/// Run the example tests of `day` of `year`, returning whether they passed
+/// and cargo's output.
+pub fn run_tests(year: u16, day: u8) -> std::io::Result<(bool, String)> {
+    let output = cargo()
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["test", "--quiet", "--lib", "--"])
+        .arg(format!("{}::day{day:02}::", Year::module(year)))
+        .output()?;
+    let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+    text.push_str(&String::from_utf8_lossy(&output.stderr));
+    Ok((output.status.success(), text))
+}
+
+/// Build and run `day` of `year` on `input`, returning its records or, when
+/// it does not build, the compiler's output.
+pub fn run_input(year: u16, day: u8, input: &Path) -> std::io::Result<Result<Vec<Record>, String>> {
+    let output = cargo()
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["run", "--quiet", "--release", "--bin", "aoc", "--", "run"])
+        .arg(day.to_string())
+        .args(["--year", &year.to_string()])
+        .arg("--input")
+        .arg(std::path::absolute(input)?)
+        .args(["--format", "csv"])
+        .output()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(report::from_csv(&stdout)
+        .ok_or_else(|| String::from_utf8_lossy(&output.stderr).into_owned()))
+}
+
+/// How a part's result compares with the previous run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// There was no previous run of this part.
+    First,
+    Same,
+    /// Holds the previous answer, or status if it was not solved.
+    Changed(String),
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::First => Ok(()),
+            Change::Same => write!(f, " (unchanged)"),
+            Change::Changed(before) => write!(f, " (was {before})"),
+        }
+    }
+}
+
+/// Compare `record` with the same part in `previous`.
+pub fn compare(previous: &[Record], record: &Record) -> Change {
+    let Some(before) = previous
+        .iter()
+        .find(|r| (r.day, r.part) == (record.day, record.part))
+    else {
+        return Change::First;
+    };
+
+    if (&before.answer, before.status) == (&record.answer, record.status) {
+        Change::Same
+    } else {
+        Change::Changed(match &before.answer {
+            Some(answer) => answer.clone(),
+            None => before.status.name().to_string(),
+        })
+    }
+}
+
+/// A part's result for display: its answer, or its status and error.
+pub fn outcome(record: &Record) -> String {
+    match (&record.answer, record.status, &record.error) {
+        (Some(answer), _, _) => answer.clone(),
+        (None, Status::Unimplemented, _) => "unimplemented".to_string(),
+        (None, status, Some(error)) => format!("{status}: {error}"),
+        (None, status, None) => status.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::scratch_dir;
+    use std::time::Duration;
+
+    #[test]
+    fn notices_modified_created_and_removed_files() {
+        let dir = scratch_dir("watch");
+        std::fs::create_dir_all(&dir).unwrap();
+        let (source, input) = (dir.join("day3.rs"), dir.join("day3.txt"));
+        std::fs::write(&source, "// v1").unwrap();
+
+        let mut watcher = Watcher::new([source.clone(), input.clone()]);
+        assert!(watcher.changed().is_empty());
+
+        let later = SystemTime::now() + Duration::from_secs(5);
+        let file = std::fs::File::options().write(true).open(&source).unwrap();
+        file.set_modified(later).unwrap();
+        std::fs::write(&input, "xmul(2,4)").unwrap();
+        assert_eq!(watcher.changed(), [source.clone(), input.clone()]);
+        assert!(watcher.changed().is_empty());
+
+        std::fs::remove_file(&input).unwrap();
+        assert_eq!(watcher.changed(), [input]);
+    }
+
+    #[test]
+    fn compares_with_the_previous_run() {
+        let record = |part, answer: Option<&str>, status| Record {
+            day: 7,
+            part,
+            answer: answer.map(String::from),
+            duration: Duration::ZERO,
+            status,
+            error: None,
+            allocs: None,
+        };
+        let previous = [
+            record(1, Some("3749"), Status::Solved),
+            record(2, None, Status::Unimplemented),
+        ];
+
+        let part1 = record(1, Some("3749"), Status::Solved);
+        assert_eq!(compare(&previous, &part1), Change::Same);
+        let part2 = record(2, Some("11387"), Status::Solved);
+        assert_eq!(
+            compare(&previous, &part2),
+            Change::Changed("unimplemented".into())
+        );
+        assert_eq!(compare(&[], &part2), Change::First);
+        assert_eq!(outcome(&previous[1]), "unimplemented");
+    }
+}
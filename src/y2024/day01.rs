@@ -0,0 +1,104 @@
+// Day 1: Historian Hysteria
+// https://adventofcode.com/2024/day/1
+
+use std::collections::HashMap;
+
+use crate::error::{self, ParseError};
+use crate::Solution;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = (Vec<i32>, Vec<i32>);
+    type Answer1 = i64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let (left_list, right_list) = input.clone();
+        total_distance(left_list, right_list)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let (left_list, right_list) = input.clone();
+        similarity_score(left_list, right_list)
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    let mut col1 = Vec::with_capacity(1000);
+    let mut col2 = Vec::with_capacity(1000);
+
+    for line in error::lines(input) {
+        if line.text.trim().is_empty() {
+            continue;
+        }
+        let (left, right) = line.split_once(line.text.trim(), " ")?;
+        col1.push(line.parse(left, "location ID")?);
+        col2.push(line.parse(right, "location ID")?);
+    }
+
+    Ok((col1, col2))
+}
+
+fn total_distance(mut left_list: Vec<i32>, mut right_list: Vec<i32>) -> i64 {
+    left_list.sort_unstable();
+    right_list.sort_unstable();
+
+    left_list
+        .iter()
+        .zip(right_list.iter())
+        .fold(0, |mut acc, (&l, &r)| {
+            acc += (l as i64 - r as i64).abs();
+            acc
+        })
+}
+
+fn similarity_score(left_list: Vec<i32>, right_list: Vec<i32>) -> u64 {
+    let count = |list: Vec<i32>| {
+        list.into_iter()
+            .fold(HashMap::<i32, i32>::with_capacity(1000), |mut acc, num| {
+                *acc.entry(num).or_insert(0) += 1;
+                acc
+            })
+    };
+    let left_count = count(left_list);
+    let right_count = count(right_list);
+
+    right_count.iter().fold(0, |mut acc, (&k, &v)| {
+        if let Some(&l) = left_count.get(&k) {
+            acc += k as u64 * v as u64 * l as u64;
+        }
+        acc
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day1::part1(&Day1::parse(EXAMPLE).unwrap()), 11);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day1::part2(&Day1::parse(EXAMPLE).unwrap()), 31);
+    }
+}
@@ -0,0 +1,108 @@
+// Day 2: Red-nosed Reports
+// https://adventofcode.com/2024/day/2
+
+use crate::error::{self, ParseError};
+use crate::Solution;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-nosed Reports";
+
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        safe_reports(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        safe_reports_dampened(input)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    let mut reports = Vec::with_capacity(1000);
+
+    for line in error::lines(input) {
+        if line.text.trim().is_empty() {
+            continue;
+        }
+        reports.push(
+            line.text
+                .split_whitespace()
+                .map(|num| line.parse(num, "level"))
+                .collect::<Result<Vec<i32>, _>>()?,
+        )
+    }
+
+    Ok(reports)
+}
+
+fn safe_reports(reports: &[Vec<i32>]) -> usize {
+    reports.iter().filter(|levels| safe_levels(levels)).count()
+}
+
+fn safe_levels(levels: &[i32]) -> bool {
+    if levels.len() < 2 {
+        return false;
+    }
+
+    let mut diffs = levels.windows(2).map(|w| w[1] - w[0]);
+    let first_diff = diffs.next().unwrap();
+
+    if first_diff == 0 || first_diff.abs() < 1 || first_diff.abs() > 3 {
+        return false;
+    }
+
+    let direction = first_diff.signum();
+
+    diffs.all(|diff| diff.signum() == direction && diff != 0 && diff.abs() >= 1 && diff.abs() <= 3)
+}
+
+fn safe_reports_dampened(reports: &[Vec<i32>]) -> usize {
+    reports
+        .iter()
+        .filter(|levels| {
+            (0..=levels.len()).any(|i| {
+                let sub_levels: Vec<_> = levels
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx, &val)| if idx != i { Some(val) } else { None })
+                    .collect();
+
+                sub_levels.len() >= 2 && safe_levels(&sub_levels)
+            })
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day2::part1(&Day2::parse(EXAMPLE).unwrap()), 2);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day2::part2(&Day2::parse(EXAMPLE).unwrap()), 4);
+    }
+}
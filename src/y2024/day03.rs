@@ -0,0 +1,105 @@
+// Day 3: Mull It Over
+// https://adventofcode.com/2024/day/3
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::error::ParseError;
+use crate::Solution;
+
+lazy_static! {
+    static ref RE_MUL: Regex = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+    static ref RE_INSTRUCTIONS: Regex =
+        Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\)").unwrap();
+}
+
+pub enum Operation {
+    Mul(i64, i64),
+    Do,
+    Dont,
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Input = (Vec<(i64, i64)>, Vec<Operation>);
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((
+            get_valid_operations(input),
+            get_valid_operations_with_do(input),
+        ))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let (valid_operations, _) = input;
+        valid_operations.iter().map(|(x, y)| x * y).sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let (_, operations_with_do) = input;
+        operations_with_do
+            .iter()
+            .fold((0, true), |(sum, enabled), op| match op {
+                Operation::Mul(x, y) if enabled => (sum + x * y, enabled),
+                Operation::Mul(_, _) => (sum, enabled),
+                Operation::Do => (sum, true),
+                Operation::Dont => (sum, false),
+            })
+            .0
+    }
+}
+
+fn get_valid_operations(input: &str) -> Vec<(i64, i64)> {
+    RE_MUL
+        .captures_iter(input)
+        .map(|cap| {
+            let x = cap[1].parse::<i64>().unwrap();
+            let y = cap[2].parse::<i64>().unwrap();
+            (x, y)
+        })
+        .collect()
+}
+
+fn get_valid_operations_with_do(input: &str) -> Vec<Operation> {
+    RE_INSTRUCTIONS
+        .captures_iter(input)
+        .filter_map(
+            |cap| match (cap.get(1), cap.get(2), cap.get(0).map(|m| m.as_str())) {
+                (Some(x_str), Some(y_str), _) => {
+                    let x = x_str.as_str().parse::<i64>().unwrap();
+                    let y = y_str.as_str().parse::<i64>().unwrap();
+                    Some(Operation::Mul(x, y))
+                }
+                (_, _, Some("do()")) => Some(Operation::Do),
+                (_, _, Some("don't()")) => Some(Operation::Dont),
+                _ => None,
+            },
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str =
+        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+    const EXAMPLE_2: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day3::part1(&Day3::parse(EXAMPLE_1).unwrap()), 161);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day3::part2(&Day3::parse(EXAMPLE_2).unwrap()), 48);
+    }
+}
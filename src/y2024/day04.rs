@@ -0,0 +1,110 @@
+// Day 4: Ceres Search
+// https://adventofcode.com/2024/day/4
+
+use crate::error::ParseError;
+use crate::image::{Picture, Rgb};
+use crate::utils::grid::Grid;
+use crate::Solution;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let vertical = input.columns().map(|line| line.copied().collect());
+        let diag1 = input.diagonals().map(|line| line.copied().collect());
+        let diag2 = input.anti_diagonals().map(|line| line.copied().collect());
+
+        input
+            .rows()
+            .map(<[u8]>::to_vec)
+            .chain(vertical)
+            .chain(diag1)
+            .chain(diag2)
+            .map(|line: Vec<u8>| count_xmas(&line))
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        window_2d_xmas(input)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |c| c as u8)
+}
+
+/// The letters, coloured by their place in `XMAS`.
+pub fn picture(input: &str) -> Result<Picture, ParseError> {
+    let grid = parse_input(input)?;
+    Ok(Picture::from_grid(&grid, |_, &c| match c {
+        b'X' => Rgb(220, 50, 47),
+        b'M' => Rgb(181, 137, 0),
+        b'A' => Rgb(133, 153, 0),
+        b'S' => Rgb(38, 139, 210),
+        _ => Rgb(88, 88, 88),
+    }))
+}
+
+fn count_xmas(input: &[u8]) -> usize {
+    input
+        .windows(4)
+        .filter(|w| w == b"XMAS" || w == b"SAMX")
+        .count()
+}
+
+fn window_2d_xmas(input: &Grid<u8>) -> usize {
+    let (m, n) = (input.height(), input.width());
+    let mut result = 0;
+
+    for i in 1..m - 1 {
+        for j in 1..n - 1 {
+            let diag1 = &[input[(i - 1, j - 1)], input[(i, j)], input[(i + 1, j + 1)]];
+            let diag2 = &[input[(i + 1, j - 1)], input[(i, j)], input[(i - 1, j + 1)]];
+
+            if (diag1 == b"MAS" || diag1 == b"SAM") && (diag2 == b"MAS" || diag2 == b"SAM") {
+                result += 1;
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day4::part1(&Day4::parse(EXAMPLE).unwrap()), 18);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day4::part2(&Day4::parse(EXAMPLE).unwrap()), 9);
+    }
+}
@@ -0,0 +1,188 @@
+// Day 5: Print Queue
+// https://adventofcode.com/2024/day/5
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::error::{self, ParseError};
+use crate::Solution;
+
+/// Page ordering rules: each page maps to the pages that must come after it.
+type Rules = HashMap<i32, HashSet<i32>>;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = (Rules, Vec<Vec<i32>>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let (rules, updates) = input;
+        updates
+            .iter()
+            .filter_map(|u| validate_update(u, rules))
+            .sum::<i32>()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let (rules, updates) = input;
+        updates
+            .iter()
+            .filter(|u| validate_update(u, rules).is_none())
+            .map(|u| correct_order(u, rules))
+            .sum::<i32>()
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Rules, Vec<Vec<i32>>), ParseError> {
+    let (mut rules, mut updates) = (HashMap::with_capacity(1000), Vec::with_capacity(200));
+    let mut sections = error::sections(input).into_iter();
+
+    for line in sections.next().unwrap_or_default() {
+        let (before, after) = line.split_once(line.text, "|")?;
+        let before: i32 = line.parse(before, "page number")?;
+        let after: i32 = line.parse(after, "page number")?;
+        rules
+            .entry(before)
+            .or_insert_with(HashSet::new)
+            .insert(after);
+    }
+
+    for line in sections.flatten() {
+        updates.push(
+            line.text
+                .split(',')
+                .map(|e| line.parse(e, "page number"))
+                .collect::<Result<_, _>>()?,
+        );
+    }
+
+    Ok((rules, updates))
+}
+
+fn validate_update(update: &[i32], rules: &Rules) -> Option<i32> {
+    let position: HashMap<i32, usize> = update.iter().enumerate().map(|(i, &p)| (p, i)).collect();
+
+    for (&before, after_pages) in rules.iter() {
+        if let Some(&before_pos) = position.get(&before) {
+            for &after in after_pages {
+                if let Some(&after_pos) = position.get(&after) {
+                    if before_pos > after_pos {
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+
+    Some(update[update.len() / 2])
+}
+
+fn correct_order(update: &[i32], rules: &Rules) -> i32 {
+    let mut graph = HashMap::new();
+    let mut in_degree = HashMap::new();
+
+    let update_set: HashSet<_> = update.iter().cloned().collect();
+    for &page in update_set.iter() {
+        graph.entry(page).or_insert_with(Vec::new);
+        in_degree.entry(page).or_insert(0);
+    }
+
+    for (&before, after_pages) in rules.iter() {
+        if update_set.contains(&before) {
+            for &after in after_pages {
+                if update_set.contains(&after) {
+                    graph.entry(before).or_insert_with(Vec::new).push(after);
+                    *in_degree.entry(after).or_insert(0) += 1;
+                }
+            }
+        }
+    }
+
+    let mut queue = VecDeque::new();
+    for (&node, &degree) in in_degree.iter() {
+        if degree == 0 {
+            queue.push_back(node);
+        }
+    }
+
+    let mut sorted = Vec::new();
+    while let Some(node) = queue.pop_front() {
+        sorted.push(node);
+
+        if let Some(neighbors) = graph.get(&node) {
+            for &neighbor in neighbors {
+                if let Some(neighbor_degree) = in_degree.get_mut(&neighbor) {
+                    *neighbor_degree -= 1;
+                    if *neighbor_degree == 0 {
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+    }
+
+    sorted[sorted.len() / 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day5::part1(&Day5::parse(EXAMPLE).unwrap()), 143);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day5::part2(&Day5::parse(EXAMPLE).unwrap()), 123);
+    }
+
+    #[test]
+    fn parse_reports_bad_rule() {
+        let err = Day5::parse("47|53\n97-13\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected `|`, found end of line"
+        );
+    }
+}
@@ -0,0 +1,230 @@
+// Day 6: Guard Gallivant
+// https://adventofcode.com/2024/day/6
+
+use std::collections::HashSet;
+
+use crate::error::ParseError;
+use crate::image::{Picture, Rgb};
+use crate::utils::geom::{Direction, Point};
+use crate::utils::grid::Grid;
+use crate::utils::parse;
+use crate::visualize::{Color, Frame, Screen};
+use crate::Solution;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Input = (Grid<u8>, Point);
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let (grid, start) = input;
+        count_visited_positions(grid.clone(), *start)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let (grid, start) = input;
+        count_possible_obstructions(grid, *start)
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Grid<u8>, Point), ParseError> {
+    let grid = parse::grid(input, "`.`, `#` or `^`", |ch| match ch {
+        '#' => Some(255),
+        '^' => Some(1),
+        '.' => Some(0),
+        _ => None,
+    })?;
+    let start = parse::find_cell(&grid, &1, input, "a guard `^`")?;
+
+    Ok((grid, start.into()))
+}
+
+fn count_visited_positions(mut grid: Grid<u8>, start: Point) -> i32 {
+    walk_guard(&mut grid, start, |_, _, _| true);
+    grid.iter().filter(|(_, &cell)| cell == 1).count() as i32
+}
+
+/// Walk the guard off the map, marking each cell visited with 1. `observe`
+/// sees every step and can stop the walk by returning `false`.
+fn walk_guard(
+    grid: &mut Grid<u8>,
+    start: Point,
+    mut observe: impl FnMut(&Grid<u8>, Point, Direction) -> bool,
+) {
+    let mut curr = start;
+    let mut direction = Direction::Up;
+
+    loop {
+        let next_pos = curr.step(direction);
+
+        let Some(next) = grid.at_mut(next_pos) else {
+            break;
+        };
+
+        if *next == 255 {
+            direction = direction.turn_right();
+            continue;
+        }
+
+        *next |= 1;
+        curr = next_pos;
+
+        if !observe(grid, curr, direction) {
+            break;
+        }
+    }
+}
+
+/// Show the guard's walk, one frame per step.
+pub fn visualize(input: &str, screen: &mut dyn Screen) -> Result<(), ParseError> {
+    let (mut grid, start) = parse_input(input)?;
+    let mut steps = 0;
+
+    walk_guard(&mut grid, start, |grid, guard, direction| {
+        steps += 1;
+        let frame = Frame::from_grid(format!("step {steps}"), grid, |pos, &cell| match cell {
+            _ if Point::from(pos) == guard => direction.arrow(),
+            255 => '#',
+            1 => 'X',
+            _ => '.',
+        });
+        screen.show(&frame)
+    });
+
+    Ok(())
+}
+
+/// The guard's route over the cells it visits.
+pub fn picture(input: &str) -> Result<Picture, ParseError> {
+    let (mut grid, start) = parse_input(input)?;
+    let mut route = vec![start];
+    walk_guard(&mut grid, start, |_, guard, _| {
+        route.push(guard);
+        true
+    });
+
+    let picture = Picture::from_grid(&grid, |_, &cell| match cell {
+        255 => Rgb(64, 64, 64),
+        1 => Rgb(150, 190, 230),
+        _ => Rgb::WHITE,
+    });
+    Ok(picture.with_path(route, Rgb(220, 50, 47)))
+}
+
+pub fn palette(c: char) -> Option<Color> {
+    match c {
+        '#' => Some(Color::Gray),
+        'X' => Some(Color::Blue),
+        '^' | '>' | 'v' | '<' => Some(Color::Yellow),
+        _ => None,
+    }
+}
+
+fn count_possible_obstructions(grid: &Grid<u8>, start: Point) -> usize {
+    fn simulate_guard(grid: &Grid<u8>, start: Point) -> bool {
+        let mut visited_states = HashSet::new();
+        let mut curr = start;
+        let mut dir = Direction::Up;
+
+        loop {
+            if !visited_states.insert((curr, dir)) {
+                return true;
+            }
+
+            let next = curr.step(dir);
+
+            match grid.at(next) {
+                None => break,
+                Some(255) => {
+                    dir = dir.turn_right();
+                    continue;
+                }
+                Some(_) => {}
+            }
+
+            curr = next;
+        }
+
+        false
+    }
+
+    let mut possible_obstructions = 0;
+
+    for (pos, &cell) in grid.iter() {
+        if Point::from(pos) == start || cell == 255 {
+            continue;
+        }
+
+        let mut modified_grid = grid.clone();
+        modified_grid[pos] = 255;
+
+        if simulate_guard(&modified_grid, start) {
+            possible_obstructions += 1;
+        }
+    }
+
+    possible_obstructions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day6::part1(&Day6::parse(EXAMPLE).unwrap()), 41);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day6::part2(&Day6::parse(EXAMPLE).unwrap()), 6);
+    }
+
+    #[test]
+    fn visualizes_every_step() {
+        let mut frames = Vec::new();
+        visualize(EXAMPLE, &mut frames).unwrap();
+        let last = frames.last().unwrap();
+        assert_eq!(last.caption, format!("step {}", frames.len()));
+        assert_eq!(last.rows[9], "......#v..");
+        let visited = last.rows.iter().flat_map(|row| row.chars());
+        assert_eq!(visited.filter(|&c| c == 'X' || c == 'v').count(), 41);
+    }
+
+    #[test]
+    fn parse_reports_unknown_cell() {
+        let err = Day6::parse("..#\n.^o\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected `.`, `#` or `^`, found `o`"
+        );
+
+        let err = Day6::parse("..#\n...\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected a guard `^`, found end of line"
+        );
+    }
+}
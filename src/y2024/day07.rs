@@ -0,0 +1,183 @@
+// Day 7: Bridge Repair
+// https://adventofcode.com/2024/day/7
+
+use crate::error::ParseError;
+use crate::utils::parse;
+use crate::Solution;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+
+    type Input = Vec<(i64, Vec<i64>)>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input
+            .iter()
+            .map(|(t, n)| valid_calibration(*t, n) as i64 * t)
+            .sum::<i64>()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        input
+            .iter()
+            .map(|(t, n)| valid_calibration_2(*t, n) as i64 * t)
+            .sum::<i64>()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(i64, Vec<i64>)>, ParseError> {
+    let mut calibrations = Vec::with_capacity(850);
+
+    for line in parse::lines(input) {
+        if line.text.trim().is_empty() {
+            continue;
+        }
+        let (target_str, nums_str) = parse::key_value(line)?;
+        let target = line.parse(target_str, "test value")?;
+        let nums = nums_str
+            .split_whitespace()
+            .map(|n| line.parse(n, "number"))
+            .collect::<Result<Vec<i64>, _>>()?;
+
+        calibrations.push((target, nums));
+    }
+
+    Ok(calibrations)
+}
+
+fn valid_calibration(target: i64, nums: &[i64]) -> bool {
+    let n = nums.len();
+    match n {
+        0 => false,
+        1 => target == nums[0],
+        _ => {
+            valid_calibration(target - nums[n - 1], &nums[0..n - 1])
+                || (target % nums[n - 1] == 0
+                    && valid_calibration(target / nums[n - 1], &nums[0..n - 1]))
+        }
+    }
+}
+
+fn valid_calibration_2(target: i64, nums: &[i64]) -> bool {
+    if nums.is_empty() {
+        return false;
+    }
+
+    fn helper(target: i64, nums: &[i64], current_value: i64) -> bool {
+        if nums.is_empty() {
+            return current_value == target;
+        }
+
+        let next = nums[0];
+        let rest = &nums[1..];
+
+        if helper(target, rest, current_value + next) {
+            return true;
+        }
+
+        if helper(target, rest, current_value * next) {
+            return true;
+        }
+
+        if let Some(concatenated) = concat(current_value, next) {
+            if helper(target, rest, concatenated) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    helper(target, &nums[1..], nums[0])
+}
+
+#[inline(always)]
+fn concat(a: i64, b: i64) -> Option<i64> {
+    format!("{}{}", a, b).parse::<i64>().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::rng::Rng;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day7::part1(&Day7::parse(EXAMPLE).unwrap()), 3749);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day7::part2(&Day7::parse(EXAMPLE).unwrap()), 11387);
+    }
+
+    /// Try every combination of operators, left to right.
+    fn brute_force(target: i64, nums: &[i64], concatenation: bool) -> bool {
+        let operators: u32 = if concatenation { 3 } else { 2 };
+        let slots = nums.len() as u32 - 1;
+        (0..operators.pow(slots)).any(|mut combination| {
+            let mut value = nums[0];
+            for &n in &nums[1..] {
+                value = match combination % operators {
+                    0 => value + n,
+                    1 => value * n,
+                    _ => format!("{value}{n}").parse().unwrap(),
+                };
+                combination /= operators;
+            }
+            value == target
+        })
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(7);
+        for _ in 0..2000 {
+            let nums: Vec<i64> = (0..rng.range(1..7)).map(|_| rng.range(1..20)).collect();
+            // Half the targets are reachable by construction.
+            let target = if rng.chance(0.5) {
+                nums.iter()
+                    .skip(1)
+                    .fold(nums[0], |acc, &n| match rng.below(3) {
+                        0 => acc + n,
+                        1 => acc * n,
+                        _ => format!("{acc}{n}").parse().unwrap(),
+                    })
+            } else {
+                rng.range(1..5000)
+            };
+
+            assert_eq!(
+                valid_calibration(target, &nums),
+                brute_force(target, &nums, false),
+                "{target}: {nums:?}"
+            );
+            assert_eq!(
+                valid_calibration_2(target, &nums),
+                brute_force(target, &nums, true),
+                "{target}: {nums:?} with concatenation"
+            );
+        }
+    }
+}
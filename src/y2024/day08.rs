@@ -0,0 +1,164 @@
+// Day 8: Resonant Collinearity
+// https://adventofcode.com/2024/day/8
+
+use std::collections::{HashMap, HashSet};
+
+use crate::error::ParseError;
+use crate::image::{Picture, Rgb};
+use crate::utils::grid::Grid;
+use crate::Solution;
+
+/// Antenna coordinates `(row, col)` grouped by frequency.
+type AntennaPositions = HashMap<u8, HashSet<(i32, i32)>>;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
+
+    type Input = (Grid<u8>, AntennaPositions);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let (grid, antenna_positions) = input;
+        count_antinodes(grid, antenna_positions)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let (grid, antenna_positions) = input;
+        count_repeating_antinodes(grid, antenna_positions)
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Grid<u8>, AntennaPositions), ParseError> {
+    let grid = Grid::parse(input, |ch| ch as u8)?;
+    let mut antenna_positions: AntennaPositions = HashMap::new();
+
+    for ((i, j), &ch) in grid.iter() {
+        if ch != b'.' {
+            antenna_positions
+                .entry(ch)
+                .or_default()
+                .insert((i as i32, j as i32));
+        }
+    }
+
+    Ok((grid, antenna_positions))
+}
+
+fn count_antinodes(grid: &Grid<u8>, antenna_positions: &AntennaPositions) -> usize {
+    let mut antinode_set: HashSet<(i32, i32)> = HashSet::new();
+
+    for positions in antenna_positions.values() {
+        if positions.len() < 2 {
+            continue;
+        }
+
+        let positions_vec: Vec<_> = positions.iter().cloned().collect();
+        for (i, &p1) in positions_vec.iter().enumerate() {
+            for &p2 in &positions_vec[i + 1..] {
+                let d = (p2.0 - p1.0, p2.1 - p1.1);
+                let n1 = (p1.0 - d.0, p1.1 - d.1);
+                let n2 = (p2.0 + d.0, p2.1 + d.1);
+
+                if grid.contains(n1.0, n1.1) {
+                    antinode_set.insert(n1);
+                }
+
+                if grid.contains(n2.0, n2.1) {
+                    antinode_set.insert(n2);
+                }
+            }
+        }
+    }
+
+    antinode_set.len()
+}
+
+fn count_repeating_antinodes(grid: &Grid<u8>, antenna_positions: &AntennaPositions) -> usize {
+    find_repeating_antinodes(grid, antenna_positions).len()
+}
+
+fn find_repeating_antinodes(
+    grid: &Grid<u8>,
+    antenna_positions: &AntennaPositions,
+) -> HashSet<(i32, i32)> {
+    let mut antinode_set: HashSet<(i32, i32)> = HashSet::new();
+
+    for positions in antenna_positions.values() {
+        if positions.len() < 2 {
+            continue;
+        }
+
+        let positions_vec: Vec<_> = positions.iter().cloned().collect();
+        for (i, &p1) in positions_vec.iter().enumerate() {
+            for &p2 in &positions_vec[i + 1..] {
+                antinode_set.insert(p1);
+                antinode_set.insert(p2);
+
+                let d = (p2.0 - p1.0, p2.1 - p1.1);
+                let mut n1 = (p1.0 - d.0, p1.1 - d.1);
+                while grid.contains(n1.0, n1.1) {
+                    antinode_set.insert(n1);
+                    n1 = (n1.0 - d.0, n1.1 - d.1);
+                }
+
+                let mut n2 = (p2.0 + d.0, p2.1 + d.1);
+
+                while grid.contains(n2.0, n2.1) {
+                    antinode_set.insert(n2);
+                    n2 = (n2.0 + d.0, n2.1 + d.1);
+                }
+            }
+        }
+    }
+
+    antinode_set
+}
+
+/// Antennas coloured by frequency over the part two antinodes.
+pub fn picture(input: &str) -> Result<Picture, ParseError> {
+    let (grid, antenna_positions) = parse_input(input)?;
+    let antinodes = find_repeating_antinodes(&grid, &antenna_positions);
+    Ok(Picture::from_grid(&grid, |(row, col), &c| match c {
+        b'.' if antinodes.contains(&(row as i32, col as i32)) => Rgb(90, 30, 30),
+        b'.' => Rgb::BLACK,
+        _ => Rgb::distinct(c as usize),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day8::part1(&Day8::parse(EXAMPLE).unwrap()), 14);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day8::part2(&Day8::parse(EXAMPLE).unwrap()), 34);
+    }
+}
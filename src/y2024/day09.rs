@@ -0,0 +1,234 @@
+// Day 9: Disk Fragmenter
+// https://adventofcode.com/2024/day/9
+
+use crate::error::{Line, ParseError};
+use crate::visualize::{Color, Frame, Screen};
+use crate::Solution;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+
+    type Input = Vec<Option<i32>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        defrag_checksum(input.clone())
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        defrag_whole_checksum(input.clone())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Option<i32>>, ParseError> {
+    let line = Line {
+        number: 1,
+        text: input.trim(),
+    };
+    let mut result = Vec::new();
+    let mut file_id = 0;
+    for (i, (at, ch)) in line.text.char_indices().enumerate() {
+        let len = ch
+            .to_digit(10)
+            .ok_or_else(|| line.error(&line.text[at..at + ch.len_utf8()], "digit"))?;
+        let to_push = if i % 2 == 0 { Some(file_id) } else { None };
+        for _ in 0..len {
+            result.push(to_push);
+        }
+        if i % 2 == 0 {
+            file_id += 1;
+        }
+    }
+
+    Ok(result)
+}
+
+fn defrag_checksum(mut disk_layout: Vec<Option<i32>>) -> i64 {
+    let mut i = 0;
+    let mut j = disk_layout.len() - 1;
+
+    while i < j {
+        while i < disk_layout.len() && disk_layout[i].is_some() {
+            i += 1;
+        }
+
+        while j > 0 && disk_layout[j].is_none() {
+            j -= 1;
+        }
+
+        disk_layout[i] = disk_layout[j];
+        disk_layout[j] = None;
+
+        i += 1;
+        j -= 1;
+    }
+
+    disk_layout
+        .iter()
+        .enumerate()
+        .map(|(idx, block)| match block {
+            Some(file_id) => idx as i64 * *file_id as i64,
+            None => 0,
+        })
+        .sum()
+}
+
+fn defrag_whole_checksum(mut disk_layout: Vec<Option<i32>>) -> i64 {
+    move_whole_files(&mut disk_layout, |_, _| true);
+
+    disk_layout
+        .iter()
+        .enumerate()
+        .map(|(idx, block)| match block {
+            Some(file_id) => idx as i64 * (*file_id as i64),
+            None => 0,
+        })
+        .sum()
+}
+
+/// Move each file, highest id first, into the leftmost gap that fits it.
+/// `observe` sees the disk after each move, along with the file moved, and
+/// can stop early by returning `false`.
+fn move_whole_files(
+    disk_layout: &mut [Option<i32>],
+    mut observe: impl FnMut(&[Option<i32>], i32) -> bool,
+) {
+    let max_file_id = match disk_layout.iter().filter_map(|&x| x).max() {
+        Some(m) => m,
+        None => return,
+    };
+
+    for file_id in (0..=max_file_id).rev() {
+        let file_positions: Vec<usize> = disk_layout
+            .iter()
+            .enumerate()
+            .filter(|(_, &b)| b == Some(file_id))
+            .map(|(i, _)| i)
+            .collect();
+
+        if file_positions.is_empty() {
+            continue;
+        }
+
+        let file_size = file_positions.len();
+        let file_leftmost = file_positions[0];
+
+        let mut candidate_start = None;
+        let mut current_count = 0;
+        let mut current_start = 0;
+
+        for (i, block) in disk_layout.iter().enumerate().take(file_leftmost) {
+            if block.is_none() {
+                if current_count == 0 {
+                    current_start = i;
+                }
+                current_count += 1;
+                if current_count == file_size {
+                    candidate_start = Some(current_start);
+                    break;
+                }
+            } else {
+                current_count = 0;
+            }
+        }
+
+        if let Some(start_pos) = candidate_start {
+            for &pos in &file_positions {
+                disk_layout[pos] = None;
+            }
+            for offset in 0..file_size {
+                disk_layout[start_pos + offset] = Some(file_id);
+            }
+
+            if !observe(disk_layout, file_id) {
+                return;
+            }
+        }
+    }
+}
+
+/// Blocks per row when drawing the disk.
+const DISK_WIDTH: usize = 100;
+
+/// Show the whole-file compaction of part two, one frame per file moved.
+/// Files are drawn as their id in base 36, wrapping past `z`.
+pub fn visualize(input: &str, screen: &mut dyn Screen) -> Result<(), ParseError> {
+    let mut disk_layout = parse_input(input)?;
+    let frame = |disk_layout: &[Option<i32>], caption| {
+        let rows = disk_layout
+            .chunks(DISK_WIDTH)
+            .map(|blocks| {
+                blocks
+                    .iter()
+                    .map(|block| match block {
+                        Some(id) => char::from_digit(*id as u32 % 36, 36).unwrap(),
+                        None => '.',
+                    })
+                    .collect()
+            })
+            .collect();
+        Frame::new(caption, rows)
+    };
+
+    if screen.show(&frame(&disk_layout, "start".to_string())) {
+        move_whole_files(&mut disk_layout, |disk_layout, file_id| {
+            screen.show(&frame(disk_layout, format!("moved file {file_id}")))
+        });
+    }
+
+    Ok(())
+}
+
+pub fn palette(c: char) -> Option<Color> {
+    const FILES: [Color; 6] = [
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+    ];
+    match c {
+        '.' => Some(Color::Gray),
+        _ => c.to_digit(36).map(|d| FILES[d as usize % FILES.len()]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2333133121414131402\n";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day9::part1(&Day9::parse(EXAMPLE).unwrap()), 1928);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day9::part2(&Day9::parse(EXAMPLE).unwrap()), 2858);
+    }
+
+    #[test]
+    fn visualizes_each_file_move() {
+        let mut frames = Vec::new();
+        visualize(EXAMPLE, &mut frames).unwrap();
+        let rows: Vec<_> = frames.iter().map(|f| f.rows[0].as_str()).collect();
+        assert_eq!(rows[0], "00...111...2...333.44.5555.6666.777.888899");
+        assert_eq!(rows[1], "0099.111...2...333.44.5555.6666.777.8888..");
+        assert_eq!(
+            rows.last(),
+            Some(&"00992111777.44.333....5555.6666.....8888..")
+        );
+        assert_eq!(frames[1].caption, "moved file 9");
+    }
+}
@@ -0,0 +1,143 @@
+// Day 10: Hoof It
+// https://adventofcode.com/2024/day/10
+
+use std::collections::HashSet;
+
+use crate::error::ParseError;
+use crate::image::{Picture, Rgb};
+use crate::utils::grid::Grid;
+use crate::utils::memo::Memo;
+use crate::utils::parse;
+use crate::Solution;
+
+/// Trailhead positions and the height of every tile.
+type TopoMap = (Vec<(usize, usize)>, Grid<i32>);
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Hoof It";
+
+    type Input = TopoMap;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let (trailheads, topo_map) = input;
+        trailheads
+            .iter()
+            .map(|&start| find_trailhead_score(start, topo_map).len() as i32)
+            .sum::<i32>()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let (trailheads, topo_map) = input;
+        let mut memo = Memo::new();
+        trailheads
+            .iter()
+            .map(|&start| find_trailhead_rating(start, topo_map, &mut memo))
+            .sum::<i32>()
+    }
+}
+
+fn parse_input(input: &str) -> Result<TopoMap, ParseError> {
+    let topo_map = parse::grid(input, "a height or `.`", |c| match c {
+        '.' => Some(-1),
+        _ => c.to_digit(10).map(|h| h as i32),
+    })?;
+
+    let trailheads = topo_map
+        .iter()
+        .filter_map(|(pos, &h)| (h == 0).then_some(pos))
+        .collect();
+
+    Ok((trailheads, topo_map))
+}
+
+/// Heights from dark (0) to bright (9) green; impassable tiles black.
+pub fn picture(input: &str) -> Result<Picture, ParseError> {
+    let (_, topo_map) = parse_input(input)?;
+    Ok(Picture::from_grid(&topo_map, |_, &h| match h {
+        0..=9 => Rgb(0, 40 + 21 * h as u8, 0),
+        _ => Rgb::BLACK,
+    }))
+}
+
+fn find_trailhead_score(pos: (usize, usize), map: &Grid<i32>) -> HashSet<(usize, usize)> {
+    match map[pos] {
+        9 => HashSet::from([pos]),
+        _ => next_neighbors(pos, map)
+            .map(|next| find_trailhead_score(next, map))
+            .fold(HashSet::new(), |mut acc, set| {
+                acc.extend(set);
+                acc
+            }),
+    }
+}
+
+/// The number of distinct trails from `pos` up to a 9. Trailheads share the
+/// upper parts of their trails, so ratings are cached by position.
+fn find_trailhead_rating(
+    pos: (usize, usize),
+    map: &Grid<i32>,
+    memo: &mut Memo<(usize, usize), i32>,
+) -> i32 {
+    memo.get(pos, |memo, &pos| match map[pos] {
+        9 => 1,
+        _ => next_neighbors(pos, map)
+            .map(|next| find_trailhead_rating(next, map, memo))
+            .sum(),
+    })
+}
+
+fn next_neighbors(
+    pos: (usize, usize),
+    map: &Grid<i32>,
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let val = map[pos];
+    map.neighbors4(pos)
+        .filter(move |&next| map[next] == val + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day10::part1(&Day10::parse(EXAMPLE).unwrap()), 36);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day10::part2(&Day10::parse(EXAMPLE).unwrap()), 81);
+    }
+
+    #[test]
+    fn trailheads_share_cached_ratings() {
+        let (trailheads, topo_map) = Day10::parse(EXAMPLE).unwrap();
+        let mut memo = Memo::new();
+        for &start in &trailheads {
+            find_trailhead_rating(start, &topo_map, &mut memo);
+        }
+        let stats = memo.stats();
+        assert_eq!(stats.misses as usize, memo.len());
+        assert!(stats.hits > 0, "{stats}");
+    }
+}
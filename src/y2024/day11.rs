@@ -0,0 +1,128 @@
+// Day 11: Plutonian Pebbles
+// https://adventofcode.com/2024/day/11
+
+use crate::error::ParseError;
+use crate::utils::memo::Memo;
+use crate::utils::parse;
+use crate::Solution;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+
+    type Input = Vec<u64>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        count_blink(input, 25)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        count_blink(input, 75)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    let stones = parse::lines(input)
+        .map(parse::uints)
+        .collect::<Result<Vec<Vec<u64>>, _>>()?;
+    Ok(stones.concat())
+}
+
+fn count_blink(nums: &[u64], blinks: usize) -> usize {
+    let mut memo = Memo::new();
+    nums.iter()
+        .map(|&num| count_stones(num, blinks, &mut memo))
+        .sum()
+}
+
+/// How many stones `num` becomes after `blinks` blinks.
+fn count_stones(num: u64, blinks: usize, memo: &mut Memo<(u64, usize), usize>) -> usize {
+    if blinks == 0 {
+        return 1;
+    }
+    memo.get((num, blinks), |memo, _| {
+        if num == 0 {
+            return count_stones(1, blinks - 1, memo);
+        }
+        let num_str = num.to_string();
+        let n = num_str.len();
+        if n.is_multiple_of(2) {
+            let (left, right) = num_str.split_at(n / 2);
+            let (left, right) = (left.parse::<u64>().unwrap(), right.parse::<u64>().unwrap());
+            count_stones(left, blinks - 1, memo) + count_stones(right, blinks - 1, memo)
+        } else {
+            count_stones(num * 2024, blinks - 1, memo)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::rng::Rng;
+
+    const EXAMPLE: &str = "125 17\n";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day11::part1(&Day11::parse(EXAMPLE).unwrap()), 55312);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            Day11::part2(&Day11::parse(EXAMPLE).unwrap()),
+            65601038650482
+        );
+    }
+
+    /// Blink the actual row of stones, one stone at a time.
+    fn brute_force(stones: &[u64], blinks: usize) -> usize {
+        let mut stones = stones.to_vec();
+        for _ in 0..blinks {
+            stones = stones
+                .into_iter()
+                .flat_map(|stone| {
+                    let digits = stone.to_string();
+                    if stone == 0 {
+                        vec![1]
+                    } else if digits.len() % 2 == 0 {
+                        let (left, right) = digits.split_at(digits.len() / 2);
+                        vec![left.parse().unwrap(), right.parse().unwrap()]
+                    } else {
+                        vec![stone * 2024]
+                    }
+                })
+                .collect();
+        }
+        stones.len()
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(11);
+        for _ in 0..200 {
+            let stones: Vec<u64> = (0..rng.range(1..5))
+                .map(|_| match rng.below(3) {
+                    0 => 0,
+                    1 => rng.range(1..100) as u64,
+                    _ => rng.range(0..1_000_000) as u64,
+                })
+                .collect();
+            let blinks = rng.below(16);
+            assert_eq!(
+                count_blink(&stones, blinks),
+                brute_force(&stones, blinks),
+                "{stones:?} after {blinks} blinks"
+            );
+        }
+    }
+}
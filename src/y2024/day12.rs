@@ -0,0 +1,294 @@
+// Day 12: Garden Groups
+// https://adventofcode.com/2024/day/12
+
+use std::collections::HashMap;
+
+use crate::error::ParseError;
+use crate::image::{Picture, Rgb};
+use crate::utils::geom::{Direction, Point};
+use crate::utils::grid::Grid;
+use crate::Solution;
+
+/// A boundary edge as a pair of grid-corner vertices.
+type Edge = ((usize, usize), (usize, usize));
+
+#[derive(Debug)]
+struct Region {
+    plant: char,
+    area: u64,
+    perimeter: u64,
+    edges: Vec<Edge>,
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Garden Groups";
+
+    type Input = Grid<char>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        // Total fence price using perimeter
+        let regions = find_regions(input);
+        regions.iter().map(|r| r.area * r.perimeter).sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        // Total fence price using number of sides
+        let regions = find_regions(input);
+        regions
+            .iter()
+            .map(|r| {
+                let sides = count_sides(&r.edges);
+                r.area * sides
+            })
+            .sum()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |c| c)
+}
+
+/// The garden coloured by plant.
+pub fn picture(input: &str) -> Result<Picture, ParseError> {
+    let farm = parse_input(input)?;
+    Ok(Picture::from_grid(&farm, |_, &plant| {
+        Rgb::distinct(plant as usize)
+    }))
+}
+
+fn find_regions(farm: &Grid<char>) -> Vec<Region> {
+    let mut visited = farm.map(|_| false);
+    let mut regions = Vec::new();
+
+    for (pos, &plant) in farm.iter() {
+        if !visited[pos] {
+            let mut region = Region {
+                plant,
+                area: 0,
+                perimeter: 0,
+                edges: Vec::new(),
+            };
+            dfs(farm, &mut visited, &mut region, pos);
+            regions.push(region);
+        }
+    }
+
+    regions
+}
+
+/// DFS to identify a region and gather area, perimeter, and boundary edges.
+fn dfs(farm: &Grid<char>, visited: &mut Grid<bool>, region: &mut Region, start: (usize, usize)) {
+    let mut stack = vec![Point::from(start)];
+    visited[start] = true;
+
+    while let Some(cell) = stack.pop() {
+        region.area += 1;
+
+        for dir in Direction::ALL {
+            let next = cell.step(dir);
+            if farm.at(next) == Some(&region.plant) {
+                // Same plant, continue region
+                if !visited[next] {
+                    visited[next] = true;
+                    stack.push(next);
+                }
+            } else {
+                // Outside the grid or a different plant -> boundary edge
+                region.perimeter += 1;
+                record_edge(region, cell, dir);
+            }
+        }
+    }
+}
+
+/// Record the boundary edge of a cell. We represent vertices at cell corners:
+/// Vertex coordinates correspond to grid intersections.
+fn record_edge(region: &mut Region, cell: Point, dir: Direction) {
+    let (row, col) = cell.to_index().expect("region cells are inside the grid");
+    let edge = match dir {
+        Direction::Up => ((row, col), (row, col + 1)),
+        Direction::Down => ((row + 1, col), (row + 1, col + 1)),
+        Direction::Left => ((row, col), (row + 1, col)),
+        Direction::Right => ((row, col + 1), (row + 1, col + 1)),
+    };
+
+    let edge_norm = if edge.0 <= edge.1 {
+        edge
+    } else {
+        (edge.1, edge.0)
+    };
+    region.edges.push(edge_norm);
+}
+
+/// Count the number of sides for the given region's boundary edges.
+///
+/// A closed rectilinear boundary has as many sides as corners. A vertex where
+/// a horizontal and a vertical edge meet is one corner; a vertex touched by
+/// four edges is where the region meets itself diagonally, and is a corner of
+/// both cells.
+fn count_sides(edges: &[Edge]) -> u64 {
+    // Horizontal and vertical edges at each vertex.
+    let mut incident: HashMap<(usize, usize), (u8, u8)> = HashMap::new();
+    for &(v1, v2) in edges {
+        let horizontal = v1.0 == v2.0;
+        for v in [v1, v2] {
+            let (h, v) = incident.entry(v).or_default();
+            if horizontal {
+                *h += 1;
+            } else {
+                *v += 1;
+            }
+        }
+    }
+
+    incident
+        .values()
+        .map(|&(h, v)| match (h, v) {
+            (2, 2) => 2,
+            (1, 1) => 1,
+            _ => 0,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::rng::Rng;
+
+    const EXAMPLE_SMALL: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC
+";
+
+    const EXAMPLE_NESTED: &str = "\
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO
+";
+
+    const EXAMPLE_LARGE: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+    const EXAMPLE_E_SHAPE: &str = "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE
+";
+
+    const EXAMPLE_DIAGONAL: &str = "\
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(Day12::part1(&Day12::parse(EXAMPLE_SMALL).unwrap()), 140);
+        assert_eq!(Day12::part1(&Day12::parse(EXAMPLE_NESTED).unwrap()), 772);
+        assert_eq!(Day12::part1(&Day12::parse(EXAMPLE_LARGE).unwrap()), 1930);
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(Day12::part2(&Day12::parse(EXAMPLE_SMALL).unwrap()), 80);
+        assert_eq!(Day12::part2(&Day12::parse(EXAMPLE_NESTED).unwrap()), 436);
+        assert_eq!(Day12::part2(&Day12::parse(EXAMPLE_E_SHAPE).unwrap()), 236);
+        assert_eq!(Day12::part2(&Day12::parse(EXAMPLE_DIAGONAL).unwrap()), 368);
+        assert_eq!(Day12::part2(&Day12::parse(EXAMPLE_LARGE).unwrap()), 1206);
+    }
+
+    /// Part two by scanning: a fenced cell edge starts a new side unless the
+    /// cell before it along the fence is in the region and fenced the same way.
+    fn brute_force(farm: &Grid<char>) -> u64 {
+        let mut region_of = farm.map(|_| usize::MAX);
+        let mut areas = Vec::new();
+        for (start, &plant) in farm.iter() {
+            if region_of[start] != usize::MAX {
+                continue;
+            }
+            let id = areas.len();
+            areas.push(0);
+            let mut stack = vec![Point::from(start)];
+            region_of[start] = id;
+            while let Some(cell) = stack.pop() {
+                areas[id] += 1;
+                for next in cell.neighbors4() {
+                    if farm.at(next) == Some(&plant) && region_of[next] == usize::MAX {
+                        region_of[next] = id;
+                        stack.push(next);
+                    }
+                }
+            }
+        }
+
+        let region = |p: Point| region_of.at(p).copied();
+        let mut sides = vec![0; areas.len()];
+        for (pos, &id) in region_of.iter() {
+            let cell = Point::from(pos);
+            for dir in Direction::ALL {
+                let fenced = |p: Point| region(p) == Some(id) && region(p.step(dir)) != Some(id);
+                if fenced(cell) && !fenced(cell.step(dir.turn_left())) {
+                    sides[id] += 1;
+                }
+            }
+        }
+
+        areas
+            .iter()
+            .zip(sides)
+            .map(|(area, sides)| area * sides)
+            .sum()
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(12);
+        for _ in 0..500 {
+            let (width, height) = (rng.range(1..9) as usize, rng.range(1..9) as usize);
+            let plants = &['A', 'B', 'C'][..rng.range(1..4) as usize];
+            let cells = (0..width * height).map(|_| *rng.pick(plants)).collect();
+            let farm = Grid::from_vec(width, height, cells);
+            assert_eq!(Day12::part2(&farm), brute_force(&farm), "\n{farm}");
+        }
+    }
+
+    #[test]
+    fn pictures_plants_in_their_own_colours() {
+        let ppm = picture(EXAMPLE_SMALL).unwrap().to_ppm(1);
+        let pixels: Vec<_> = ppm[ppm.len() - 4 * 4 * 3..].chunks(3).collect();
+        let at = |row: usize, col: usize| pixels[row * 4 + col];
+        assert_eq!(at(0, 0), at(0, 3));
+        assert_eq!(at(1, 2), at(3, 3));
+        assert_ne!(at(0, 0), at(1, 0));
+        assert_ne!(at(1, 2), at(1, 3));
+    }
+}
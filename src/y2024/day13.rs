@@ -0,0 +1,209 @@
+// Day 13: Claw Contraption
+// https://adventofcode.com/2024/day/13
+
+use crate::error::{Line, ParseError};
+use crate::utils::parse;
+use crate::Solution;
+
+#[derive(Debug)]
+pub struct Claw {
+    x1: i64,
+    y1: i64,
+    x2: i64,
+    y2: i64,
+    xt: i64,
+    yt: i64,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Claw Contraption";
+
+    type Input = Vec<Claw>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input
+            .iter()
+            .map(|c| match play_claw(c) {
+                Some((a, b)) if (0..=100).contains(&a) && (0..=100).contains(&b) => 3 * a + b,
+                _ => 0,
+            })
+            .sum::<i64>()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        input
+            .iter()
+            .map(|c| Claw {
+                x1: c.x1,
+                y1: c.y1,
+                x2: c.x2,
+                y2: c.y2,
+                xt: 10000000000000 + c.xt,
+                yt: 10000000000000 + c.yt,
+            })
+            .map(|c| match play_claw(&c) {
+                Some((a, b)) if a >= 0 && b >= 0 => 3 * a + b,
+                _ => 0,
+            })
+            .sum::<i64>()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Claw>, ParseError> {
+    let mut claws = Vec::new();
+
+    for machine in parse::sections(input) {
+        let after = machine[machine.len() - 1].number + 1;
+        let mut lines = machine.iter();
+        let mut pair = |prefix| match lines.next() {
+            Some(&line) => parse_pair(line, prefix),
+            None => Err(ParseError::end_of_input(after, format!("`{prefix}`"))),
+        };
+
+        let (x1, y1) = pair("Button A: X+")?;
+        let (x2, y2) = pair("Button B: X+")?;
+        let (xt, yt) = pair("Prize: X=")?;
+        if let Some(extra) = lines.next() {
+            return Err(extra.error(extra.text, "a blank line between machines"));
+        }
+
+        claws.push(Claw {
+            x1,
+            y1,
+            x2,
+            y2,
+            xt,
+            yt,
+        });
+    }
+
+    Ok(claws)
+}
+
+/// Parse the two integers of a line starting with `prefix`, e.g.
+/// `Button A: X+94, Y+34`.
+fn parse_pair(line: Line, prefix: &str) -> Result<(i64, i64), ParseError> {
+    line.strip_prefix(prefix)?;
+    let [x, y] = parse::int_array(line)?;
+    Ok((x, y))
+}
+
+fn play_claw(claw: &Claw) -> Option<(i64, i64)> {
+    let d = claw.x1 * claw.y2 - claw.x2 * claw.y1;
+    let da = claw.y2 * claw.xt - claw.x2 * claw.yt;
+    let db = claw.x1 * claw.yt - claw.y1 * claw.xt;
+    // println!("d={d} da={da} db={db}");
+
+    match (da % d, db % d) {
+        (0, 0) => Some((da / d, db / d)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::rng::Rng;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    fn parse_example() -> Vec<Claw> {
+        Day13::parse(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day13::part1(&parse_example()), 480);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day13::part2(&parse_example()), 875318608908);
+    }
+
+    #[test]
+    fn parse_reports_truncated_machine() {
+        let err = Day13::parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected `Prize: X=`, found end of line"
+        );
+
+        let err = Day13::parse("Button A: X+94, Y+3x\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 19, "3x"));
+    }
+
+    #[test]
+    fn parses_crlf_like_lf() {
+        let crlf = Day13::parse(&EXAMPLE.replace('\n', "\r\n")).unwrap();
+        assert_eq!(crlf.len(), 4);
+        assert_eq!(Day13::part1(&crlf), 480);
+    }
+
+    /// The cheapest presses of at most 100 each that reach the prize.
+    fn brute_force(claw: &Claw) -> Option<(i64, i64)> {
+        (0..=100)
+            .flat_map(|a| (0..=100).map(move |b| (a, b)))
+            .filter(|&(a, b)| {
+                a * claw.x1 + b * claw.x2 == claw.xt && a * claw.y1 + b * claw.y2 == claw.yt
+            })
+            .min_by_key(|&(a, b)| 3 * a + b)
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(13);
+        for _ in 0..500 {
+            let mut button = || (rng.range(1..100), rng.range(1..100));
+            let ((x1, y1), (x2, y2)) = (button(), button());
+            // Puzzle inputs never have parallel buttons, which Cramer's rule
+            // cannot solve.
+            if x1 * y2 == x2 * y1 {
+                continue;
+            }
+            let (xt, yt) = if rng.chance(0.5) {
+                let (a, b) = (rng.range(0..101), rng.range(0..101));
+                (a * x1 + b * x2, a * y1 + b * y2)
+            } else {
+                (rng.range(0..20_000), rng.range(0..20_000))
+            };
+            let claw = Claw {
+                x1,
+                y1,
+                x2,
+                y2,
+                xt,
+                yt,
+            };
+
+            let solved =
+                play_claw(&claw).filter(|&(a, b)| (0..=100).contains(&a) && (0..=100).contains(&b));
+            assert_eq!(solved, brute_force(&claw), "{claw:?}");
+        }
+    }
+}
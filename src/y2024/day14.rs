@@ -0,0 +1,191 @@
+// Day 14: Restroom Redoubt
+// https://adventofcode.com/2024/day/14
+
+use crate::error::ParseError;
+use crate::image::{Picture, Rgb};
+use crate::utils::grid::Grid;
+use crate::utils::parse;
+use crate::visualize::{Color, Frame, Screen};
+use crate::Solution;
+
+#[derive(Debug)]
+pub struct Robot {
+    ox: i32,
+    oy: i32,
+    vx: i32,
+    vy: i32,
+}
+
+const LX: i32 = 101;
+const LY: i32 = 103;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Restroom Redoubt";
+
+    type Input = Vec<Robot>;
+    type Answer1 = i64;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        calculate_safety_factor(input, 100, LX, LY)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        find_christmas_tree_time(input)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Robot>, ParseError> {
+    parse::lines(input)
+        .filter(|line| !line.text.trim().is_empty())
+        .map(|line| {
+            // `p=0,4 v=3,-3`
+            line.strip_prefix("p=")?;
+            let [ox, oy, vx, vy] = parse::int_array(line)?;
+            Ok(Robot { ox, oy, vx, vy })
+        })
+        .collect()
+}
+
+fn calculate_safety_factor(robots: &[Robot], n: i32, lx: i32, ly: i32) -> i64 {
+    let mut quadrant_counts = [0i64; 4];
+    let midx = lx / 2;
+    let midy = ly / 2;
+
+    for robot in robots {
+        let cx = (robot.ox + n * robot.vx).rem_euclid(lx);
+        let cy = (robot.oy + n * robot.vy).rem_euclid(ly);
+
+        if cx > midx && cy > midy {
+            quadrant_counts[0] += 1;
+        } else if cx > midx && cy < midy {
+            quadrant_counts[1] += 1;
+        } else if cx < midx && cy < midy {
+            quadrant_counts[2] += 1;
+        } else if cx < midx && cy > midy {
+            quadrant_counts[3] += 1;
+        }
+    }
+
+    quadrant_counts.iter().product()
+}
+
+/// Show the robots second by second until their positions repeat, which
+/// takes `LX * LY` seconds since both are prime.
+pub fn visualize(input: &str, screen: &mut dyn Screen) -> Result<(), ParseError> {
+    let robots = parse_input(input)?;
+    visualize_area(&robots, LX, LY, screen);
+    Ok(())
+}
+
+fn visualize_area(robots: &[Robot], lx: i32, ly: i32, screen: &mut dyn Screen) {
+    for n in 0..lx * ly {
+        let mut counts = vec![vec![0u32; lx as usize]; ly as usize];
+        for robot in robots {
+            let cx = (robot.ox + n * robot.vx).rem_euclid(lx);
+            let cy = (robot.oy + n * robot.vy).rem_euclid(ly);
+            counts[cy as usize][cx as usize] += 1;
+        }
+
+        let rows = counts
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&count| match count {
+                        0 => '.',
+                        1..=9 => char::from_digit(count, 10).unwrap(),
+                        _ => '+',
+                    })
+                    .collect()
+            })
+            .collect();
+        let caption = format!(
+            "second {n}, safety factor {}",
+            calculate_safety_factor(robots, n, lx, ly)
+        );
+        if !screen.show(&Frame::new(caption, rows)) {
+            break;
+        }
+    }
+}
+
+/// Where the robots are after 100 seconds.
+pub fn picture(input: &str) -> Result<Picture, ParseError> {
+    let robots = parse_input(input)?;
+    let mut room = Grid::new(LX as usize, LY as usize, false);
+    for robot in &robots {
+        let cx = (robot.ox + 100 * robot.vx).rem_euclid(LX);
+        let cy = (robot.oy + 100 * robot.vy).rem_euclid(LY);
+        room[(cy as usize, cx as usize)] = true;
+    }
+    Ok(Picture::from_grid(&room, |_, &robot| match robot {
+        true => Rgb(80, 220, 100),
+        false => Rgb::BLACK,
+    }))
+}
+
+pub fn palette(c: char) -> Option<Color> {
+    match c {
+        '.' => Some(Color::Gray),
+        _ => Some(Color::Green),
+    }
+}
+
+fn find_christmas_tree_time(_robots: &[Robot]) -> i32 {
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+    #[test]
+    fn part1_example() {
+        // The example room is 11 tiles wide and 7 tall.
+        let robots = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(calculate_safety_factor(&robots, 100, 11, 7), 12);
+    }
+
+    #[test]
+    fn visualizes_until_positions_repeat() {
+        let robots = Day14::parse(EXAMPLE).unwrap();
+        let mut frames = Vec::new();
+        visualize_area(&robots, 11, 7, &mut frames);
+        assert_eq!(frames.len(), 77);
+        assert_eq!(
+            frames[0].rows,
+            [
+                "1.12.......",
+                "...........",
+                "...........",
+                "......11.11",
+                "1.1........",
+                ".........1.",
+                ".......1...",
+            ]
+        );
+        assert_eq!(frames[100 % 77].caption, "second 23, safety factor 12");
+    }
+}
@@ -0,0 +1,289 @@
+// Day 15: Warehouse Woes
+// https://adventofcode.com/2024/day/15
+
+use crate::error::ParseError;
+use crate::image::{Picture, Rgb};
+use crate::utils::geom::{Direction, Point};
+use crate::utils::grid::Grid;
+use crate::utils::parse;
+use crate::visualize::{Color, Frame, Screen};
+use crate::Solution;
+
+/// Warehouse map, robot moves and the robot's start.
+type Warehouse = (Grid<char>, Vec<Direction>, Point);
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Warehouse Woes";
+
+    type Input = Warehouse;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let (grid, moves, start) = input;
+        let new_grid = execute_moves(moves, grid, *start);
+        calculate_gps_sum(&new_grid)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let (grid, moves, start) = input;
+        let wide_grid = resize_grid(grid);
+        let new_wide_grid =
+            execute_moves_wide(moves, &wide_grid, Point::new(start.row, start.col * 2));
+        calculate_gps_sum_wide(&new_wide_grid)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Warehouse, ParseError> {
+    let mut sections = parse::sections(input).into_iter();
+    let map = sections.next().unwrap_or_default();
+    // Blank lines ahead of the map keep grid errors on the right line.
+    let top = map.first().map_or(1, |line| line.number);
+    let rows: Vec<_> = map.iter().map(|line| line.text).collect();
+    let map = "\n".repeat(top - 1) + &rows.join("\n");
+    let grid = parse::grid(&map, "`#`, `.`, `O` or `@`", |c| {
+        "#.O@".contains(c).then_some(c)
+    })?;
+    let start = parse::find_cell(&grid, &'@', &map, "a robot `@`")?.into();
+
+    let mut moves = Vec::new();
+    for line in sections.flatten() {
+        for (i, c) in line.text.char_indices() {
+            if c.is_whitespace() {
+                continue;
+            }
+            let dir = Direction::from_arrow(c).ok_or_else(|| {
+                line.error(
+                    &line.text[i..i + c.len_utf8()],
+                    "a move `^`, `>`, `v` or `<`",
+                )
+            })?;
+            moves.push(dir);
+        }
+    }
+
+    Ok((grid, moves, start))
+}
+
+fn execute_moves(moves: &[Direction], grid: &Grid<char>, start: Point) -> Grid<char> {
+    execute_moves_observed(moves, grid, start, |_, _| true)
+}
+
+/// Like [`execute_moves`], but `observe` sees the grid after each move,
+/// along with how many moves have been made, and can stop the robot early by
+/// returning `false`.
+fn execute_moves_observed(
+    moves: &[Direction],
+    grid: &Grid<char>,
+    start: Point,
+    mut observe: impl FnMut(&Grid<char>, usize) -> bool,
+) -> Grid<char> {
+    let mut grid = grid.clone();
+    let mut curr = start;
+
+    for (i, &dir) in moves.iter().enumerate() {
+        let next = curr.step(dir);
+
+        let Some(&next_cell) = grid.at(next) else {
+            continue;
+        };
+
+        match next_cell {
+            '#' => {}
+            '.' => {
+                grid[curr] = '.';
+                curr = next;
+                grid[curr] = '@';
+            }
+            'O' => {
+                let mut chain = vec![next];
+                let mut box_pos = next;
+
+                loop {
+                    let beyond = box_pos.step(dir);
+
+                    match grid.at(beyond) {
+                        Some('.') => {
+                            chain.push(beyond);
+                            break;
+                        }
+                        Some('O') => {
+                            chain.push(beyond);
+                            box_pos = beyond;
+                        }
+                        _ => {
+                            chain.clear();
+                            break;
+                        }
+                    }
+                }
+
+                if chain.len() > 1 {
+                    let free_spot = chain.pop().unwrap();
+
+                    chain.reverse();
+                    let mut prev = free_spot;
+                    for box_pos in chain {
+                        grid[prev] = 'O';
+                        grid[box_pos] = '.';
+                        prev = box_pos;
+                    }
+
+                    grid[curr] = '.';
+                    grid[next] = '@';
+                    curr = next;
+                }
+            }
+            _ => {
+                unreachable!();
+            }
+        }
+
+        if !observe(&grid, i + 1) {
+            break;
+        }
+    }
+
+    grid
+}
+
+/// Show the robot pushing boxes around, one frame per move.
+pub fn visualize(input: &str, screen: &mut dyn Screen) -> Result<(), ParseError> {
+    let (grid, moves, start) = parse_input(input)?;
+    let caption = |made: usize| match moves.get(made) {
+        Some(next) => format!("move {made}/{}, next {}", moves.len(), next.arrow()),
+        None => format!("move {made}/{}", moves.len()),
+    };
+
+    if screen.show(&Frame::from_grid(caption(0), &grid, |_, &c| c)) {
+        execute_moves_observed(&moves, &grid, start, |grid, made| {
+            screen.show(&Frame::from_grid(caption(made), grid, |_, &c| c))
+        });
+    }
+
+    Ok(())
+}
+
+/// The warehouse after every move.
+pub fn picture(input: &str) -> Result<Picture, ParseError> {
+    let (grid, moves, start) = parse_input(input)?;
+    let grid = execute_moves(&moves, &grid, start);
+    Ok(Picture::from_grid(&grid, |_, &c| match c {
+        '#' => Rgb(64, 64, 64),
+        'O' | '[' | ']' => Rgb(181, 137, 0),
+        '@' => Rgb(220, 50, 47),
+        _ => Rgb::WHITE,
+    }))
+}
+
+pub fn palette(c: char) -> Option<Color> {
+    match c {
+        '#' => Some(Color::Gray),
+        'O' | '[' | ']' => Some(Color::Yellow),
+        '@' => Some(Color::Red),
+        _ => None,
+    }
+}
+
+fn calculate_gps_sum(grid: &Grid<char>) -> i32 {
+    grid.iter()
+        .filter(|(_, &cell)| cell == 'O')
+        .map(|((i, j), _)| (100 * i + j) as i32)
+        .sum()
+}
+
+fn resize_grid(_grid: &Grid<char>) -> Grid<char> {
+    todo!()
+}
+
+fn execute_moves_wide(_moves: &[Direction], _grid: &Grid<char>, _start: Point) -> Grid<char> {
+    todo!()
+}
+
+fn calculate_gps_sum_wide(_grid: &Grid<char>) -> i32 {
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_SMALL: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+    const EXAMPLE_LARGE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(Day15::part1(&Day15::parse(EXAMPLE_SMALL).unwrap()), 2028);
+        assert_eq!(Day15::part1(&Day15::parse(EXAMPLE_LARGE).unwrap()), 10092);
+    }
+
+    #[test]
+    fn parse_reports_bad_move() {
+        let err = Day15::parse("#@.#\n\n<>\n^x\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (4, 2, "x"));
+
+        let err = Day15::parse("#O.#\n\n<>\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a robot `@`, found end of line"
+        );
+    }
+
+    #[test]
+    fn visualizes_every_move() {
+        let mut frames = Vec::new();
+        visualize(EXAMPLE_SMALL, &mut frames).unwrap();
+        assert_eq!(frames.len(), 16);
+        assert_eq!(frames[0].caption, "move 0/15, next <");
+        assert_eq!(frames[15].caption, "move 15/15");
+        assert_eq!(frames[15].rows[1], "#....OO#");
+        assert_eq!(frames[15].rows[4], "#.#O@..#");
+    }
+
+    #[test]
+    #[ignore = "part two is not implemented yet"]
+    fn part2_example() {
+        assert_eq!(Day15::part2(&Day15::parse(EXAMPLE_LARGE).unwrap()), 9021);
+    }
+}
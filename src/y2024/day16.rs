@@ -0,0 +1,190 @@
+// Day 16: Reindeer Maze
+// https://adventofcode.com/2024/day/16
+
+use crate::error::ParseError;
+use crate::image::{Picture, Rgb};
+use crate::utils::geom::{Direction, Point};
+use crate::utils::grid::Grid;
+use crate::utils::parse;
+use crate::utils::pathfinding::Paths;
+use crate::Solution;
+
+/// A reindeer's tile and facing.
+type Reindeer = (Point, Direction);
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Reindeer Maze";
+
+    type Input = Maze;
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let (grid, start, end) = input;
+        let paths = search(grid, start, end, scores(grid).costs_only());
+        paths.goal_cost().unwrap_or(u64::MAX)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let (grid, start, end) = input;
+        let paths = search(grid, start, end, scores(grid).costs_only());
+        let lowest_score = paths.goal_cost().unwrap_or(u64::MAX);
+        let best_path_tiles = find_best_path_tiles(grid, start, end, &paths, lowest_score);
+        best_path_tiles.iter().filter(|(_, &tile)| tile).count()
+    }
+}
+
+/// Maze grid with its start and end tiles.
+type Maze = (Grid<char>, Point, Point);
+
+fn parse_input(input: &str) -> Result<Maze, ParseError> {
+    let grid = parse::grid(input, "`#`, `.`, `S` or `E`", |c| {
+        "#.SE".contains(c).then_some(c)
+    })?;
+    let start = parse::find_cell(&grid, &'S', input, "a start tile `S`")?.into();
+    let end = parse::find_cell(&grid, &'E', input, "an end tile `E`")?.into();
+
+    Ok((grid, start, end))
+}
+
+/// Search paths with the best score per tile and facing kept in a grid.
+type Scored = Paths<Reindeer, Grid<[Option<u64>; 4]>>;
+
+fn scores(grid: &Grid<char>) -> Scored {
+    Paths::with_store(grid.map(|_| [None; 4]))
+}
+
+/// Search from the start, facing east, until the end tile is reached.
+fn search(grid: &Grid<char>, start: &Point, end: &Point, paths: Scored) -> Scored {
+    let successors = |&(pos, dir): &Reindeer| {
+        let next = pos.step(dir);
+        let forward = match grid.at(next) {
+            Some('#') | None => None,
+            Some(_) => Some(((next, dir), 1)),
+        };
+
+        forward.into_iter().chain([
+            ((pos, dir.turn_left()), 1000),
+            ((pos, dir.turn_right()), 1000),
+        ])
+    };
+
+    paths.dijkstra([(*start, Direction::Right)], successors, |&(pos, _)| {
+        pos == *end
+    })
+}
+
+/// The maze with one cheapest route from start to end.
+pub fn picture(input: &str) -> Result<Picture, ParseError> {
+    let (grid, start, end) = parse_input(input)?;
+    let paths = search(&grid, &start, &end, scores(&grid));
+    let mut route: Vec<Point> = paths
+        .goal
+        .and_then(|goal| paths.path_to(&goal))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect();
+    // Turning on the spot repeats a tile.
+    route.dedup();
+
+    let picture = Picture::from_grid(&grid, |_, &c| match c {
+        '#' => Rgb(64, 64, 64),
+        'S' | 'E' => Rgb(38, 139, 210),
+        _ => Rgb::WHITE,
+    });
+    Ok(picture.with_path(route, Rgb(220, 50, 47)))
+}
+
+fn find_best_path_tiles(
+    _grid: &Grid<char>,
+    _start: &Point,
+    _end: &Point,
+    _paths: &Scored,
+    _end_cost: u64,
+) -> Grid<bool> {
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+    const EXAMPLE_2: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(Day16::part1(&Day16::parse(EXAMPLE_1).unwrap()), 7036);
+        assert_eq!(Day16::part1(&Day16::parse(EXAMPLE_2).unwrap()), 11048);
+    }
+
+    #[test]
+    #[ignore = "part two is not implemented yet"]
+    fn part2_examples() {
+        assert_eq!(Day16::part2(&Day16::parse(EXAMPLE_1).unwrap()), 45);
+        assert_eq!(Day16::part2(&Day16::parse(EXAMPLE_2).unwrap()), 64);
+    }
+
+    #[test]
+    fn parse_requires_start_and_end() {
+        let err = Day16::parse("#####\n#S..#\n#####\n").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (4, "an end tile `E`"));
+        let err = Day16::parse("#####\n#..E#\n#####\n").unwrap_err();
+        assert_eq!(err.expected, "a start tile `S`");
+    }
+
+    #[test]
+    fn pictures_a_cheapest_route() {
+        let svg = picture(EXAMPLE_1).unwrap().to_svg();
+        let (_, points) = svg.split_once("<polyline points=\"").unwrap();
+        let (points, _) = points.split_once('"').unwrap();
+        let route: Vec<_> = points.split(' ').collect();
+        // 36 steps forward; the 7 turns stay on their tiles.
+        assert_eq!(route.len(), 37);
+        assert_eq!(route.first(), Some(&"1.5,13.5"));
+        assert_eq!(route.last(), Some(&"13.5,1.5"));
+    }
+}
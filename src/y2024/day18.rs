@@ -0,0 +1,208 @@
+// Day 18: RAM Run
+// https://adventofcode.com/2024/day/18
+
+use crate::error::ParseError;
+use crate::image::{Picture, Rgb};
+use crate::utils::geom::Point;
+use crate::utils::grid::Grid;
+use crate::utils::parse;
+use crate::utils::pathfinding::Paths;
+use crate::Solution;
+
+/// Width and height of the memory space.
+const SIZE: usize = 71;
+/// Number of bytes that have fallen for part one.
+const FALLEN: usize = 1024;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "RAM Run";
+
+    type Input = Vec<(usize, usize)>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let (grid, start, end) = generate_grid(&input[..FALLEN.min(input.len())], SIZE);
+        match find_shortest_step(&grid, &start, &end) {
+            Some(steps) => steps.to_string(),
+            None => "none".to_string(),
+        }
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let (_, start, end) = generate_grid(&[], SIZE);
+        match find_first_blocking_byte(input, SIZE, &start, &end) {
+            Some((x, y)) => format!("{x},{y}"),
+            None => "none".to_string(),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    parse::lines(input)
+        .filter(|line| !line.text.trim().is_empty())
+        .map(|line| {
+            let [x, y] = parse::uint_array(line)?;
+            let (x_text, y_text) = line.split_once(line.text, ",")?;
+            for (value, text) in [(x, x_text), (y, y_text)] {
+                if value >= SIZE {
+                    let expected = format!("a coordinate below {SIZE}");
+                    return Err(line.error(text.trim(), expected));
+                }
+            }
+            Ok((x, y))
+        })
+        .collect()
+}
+
+/// Memory grid with its start and end tiles.
+type Maze = (Grid<char>, Point, Point);
+
+fn generate_grid(bytes: &[(usize, usize)], size: usize) -> Maze {
+    let mut grid = Grid::new(size, size, '.');
+
+    for &(x, y) in bytes {
+        grid[(y, x)] = '#';
+    }
+
+    let last = size as i32 - 1;
+    (grid, Point::ORIGIN, Point::new(last, last))
+}
+
+/// Search paths with the steps to each tile kept in a grid.
+type Steps = Paths<Point, Grid<Option<u64>>>;
+
+fn steps(grid: &Grid<char>) -> Steps {
+    Paths::with_store(grid.map(|_| None))
+}
+
+fn find_shortest_step(grid: &Grid<char>, start: &Point, end: &Point) -> Option<u64> {
+    search(grid, start, end, steps(grid).costs_only()).goal_cost()
+}
+
+fn search(grid: &Grid<char>, start: &Point, end: &Point, paths: Steps) -> Steps {
+    let successors = |&pos: &Point| {
+        pos.neighbors4()
+            .filter(|&next| grid.at(next).is_some_and(|&cell| cell != '#'))
+    };
+    paths.bfs([*start], successors, |pos| pos == end)
+}
+
+/// The part one memory space with a shortest route through it.
+pub fn picture(input: &str) -> Result<Picture, ParseError> {
+    let bytes = parse_input(input)?;
+    let (grid, start, end) = generate_grid(&bytes[..FALLEN.min(bytes.len())], SIZE);
+    let route = search(&grid, &start, &end, steps(&grid))
+        .path_to(&end)
+        .unwrap_or_default();
+
+    let picture = Picture::from_grid(&grid, |_, &c| match c {
+        '#' => Rgb(64, 64, 64),
+        _ => Rgb::WHITE,
+    });
+    Ok(picture.with_path(route, Rgb(220, 50, 47)))
+}
+
+fn find_first_blocking_byte(
+    bytes: &[(usize, usize)],
+    size: usize,
+    start: &Point,
+    end: &Point,
+) -> Option<(usize, usize)> {
+    let blocked = |fallen: usize| {
+        find_shortest_step(&generate_grid(&bytes[..fallen], size).0, start, end).is_none()
+    };
+    if !blocked(bytes.len()) {
+        return None;
+    }
+
+    // Once the exit is cut off, more bytes keep it so: find the first count
+    // that blocks, knowing `low` bytes do not and `high` bytes do.
+    let (mut low, mut high) = (0, bytes.len());
+    while high - low > 1 {
+        let mid = (low + high) / 2;
+        if blocked(mid) {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+
+    Some(bytes[high - 1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+    // The example memory space is 7x7 with 12 fallen bytes.
+    const EXAMPLE_SIZE: usize = 7;
+    const EXAMPLE_FALLEN: usize = 12;
+
+    #[test]
+    fn part1_example() {
+        let bytes = Day18::parse(EXAMPLE).unwrap();
+        let (grid, start, end) = generate_grid(&bytes[0..EXAMPLE_FALLEN], EXAMPLE_SIZE);
+        assert_eq!(find_shortest_step(&grid, &start, &end), Some(22));
+    }
+
+    #[test]
+    fn parse_rejects_bytes_outside_the_memory_space() {
+        assert_eq!(
+            Day18::parse("1,2\n3, 71\n"),
+            Err(ParseError::new(2, 4, "a coordinate below 71", "71"))
+        );
+        assert_eq!(Day18::parse("1 2\n"), Err(ParseError::new(1, 4, "`,`", "")));
+    }
+
+    #[test]
+    fn part1_with_fewer_bytes_than_fallen() {
+        assert_eq!(Day18::part1(&vec![(1, 0), (0, 1)]), "none");
+        assert_eq!(Day18::part1(&Vec::new()), "140");
+    }
+
+    #[test]
+    fn part2_example() {
+        let bytes = Day18::parse(EXAMPLE).unwrap();
+        let (_, start, end) = generate_grid(&[], EXAMPLE_SIZE);
+        assert_eq!(
+            find_first_blocking_byte(&bytes, EXAMPLE_SIZE, &start, &end),
+            Some((6, 1))
+        );
+    }
+}
@@ -0,0 +1,150 @@
+// Day 19: Linen Layout
+// https://adventofcode.com/2024/day/19
+
+use std::collections::HashSet;
+
+use crate::error::{Line, ParseError};
+use crate::utils::memo::Memo;
+use crate::utils::parse;
+use crate::Solution;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Linen Layout";
+
+    type Input = (HashSet<String>, Vec<String>);
+    type Answer1 = i32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let (towels, designs) = input;
+        designs
+            .iter()
+            .map(|d| is_design_possible(d, towels) as i32)
+            .sum::<i32>()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let (towels, designs) = input;
+        designs
+            .iter()
+            .map(|d| possible_arrangements(d, towels))
+            .sum::<u64>()
+    }
+}
+
+fn parse_input(input: &str) -> Result<(HashSet<String>, Vec<String>), ParseError> {
+    let mut sections = parse::sections(input).into_iter();
+    let towels = match sections.next().as_deref() {
+        Some([line]) => line
+            .text
+            .split(',')
+            .map(|towel| stripes(*line, towel))
+            .collect::<Result<_, _>>()?,
+        Some([_, extra, ..]) => {
+            return Err(extra.error(extra.text, "a blank line after the towel patterns"))
+        }
+        _ => return Err(ParseError::end_of_input(1, "towel patterns")),
+    };
+    let designs = sections
+        .flatten()
+        .map(|line| stripes(line, line.text))
+        .collect::<Result<_, _>>()?;
+
+    Ok((towels, designs))
+}
+
+/// `text`, a slice of `line`, as a run of stripe colours.
+fn stripes(line: Line, text: &str) -> Result<String, ParseError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(line.error(text, "stripe colours"));
+    }
+    match text.char_indices().find(|&(_, c)| !"wubrg".contains(c)) {
+        Some((at, c)) => Err(line.error(
+            &text[at..at + c.len_utf8()],
+            "a stripe colour `w`, `u`, `b`, `r` or `g`",
+        )),
+        None => Ok(text.to_string()),
+    }
+}
+
+fn is_design_possible(design: &str, towels: &HashSet<String>) -> bool {
+    possible_arrangements(design, towels) > 0
+}
+
+fn possible_arrangements(design: &str, towels: &HashSet<String>) -> u64 {
+    let mut memo = Memo::dense(design.len() + 1);
+    arrangements_from(0, design, towels, &mut memo)
+}
+
+/// The ways to make `design[start..]` from the towels.
+fn arrangements_from(
+    start: usize,
+    design: &str,
+    towels: &HashSet<String>,
+    memo: &mut Memo<usize, u64, Vec<Option<u64>>>,
+) -> u64 {
+    if start == design.len() {
+        return 1;
+    }
+    memo.get(start, |memo, &start| {
+        towels
+            .iter()
+            .filter(|towel| design[start..].starts_with(towel.as_str()))
+            .map(|towel| arrangements_from(start + towel.len(), design, towels, memo))
+            .sum()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day19::part1(&Day19::parse(EXAMPLE).unwrap()), 6);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day19::part2(&Day19::parse(EXAMPLE).unwrap()), 16);
+    }
+
+    #[test]
+    fn parse_reports_bad_stripes() {
+        let err = Day19::parse("r, wx, b\n\nbrwrr\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 5, "x"));
+
+        let err = Day19::parse("r, , b\n\nbrwrr\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: expected stripe colours, found end of line"
+        );
+
+        let err = Day19::parse("r, wr\nbrwrr\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (2, "a blank line after the towel patterns")
+        );
+    }
+}
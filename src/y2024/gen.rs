@@ -0,0 +1,469 @@
+// Random puzzle inputs for stress testing and benchmarking beyond the real
+// input sizes. Every generator is deterministic in its seed and produces
+// input the day's parser accepts; what `size` scales is listed per day in
+// `SIZES`.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::utils::geom::{Direction, Point};
+use crate::utils::grid::Grid;
+use crate::utils::rng::Rng;
+
+/// For each day, what `size` counts and roughly its value in a real input.
+pub const SIZES: [(u8, &str, usize); 19] = [
+    (1, "pairs of location IDs", 1000),
+    (2, "reports", 1000),
+    (3, "instructions", 700),
+    (4, "grid side", 140),
+    (5, "updates", 200),
+    (6, "grid side", 130),
+    (7, "equations", 850),
+    (8, "grid side", 50),
+    (9, "files", 10000),
+    (10, "grid side", 45),
+    (11, "stones", 8),
+    (12, "grid side", 140),
+    (13, "claw machines", 320),
+    (14, "robots", 500),
+    (15, "grid side", 50),
+    (16, "grid side", 141),
+    (17, "octal digits of register A", 16),
+    (18, "falling bytes", 3450),
+    (19, "designs", 400),
+];
+
+/// The size of a real input for `day`, if it has a generator.
+pub fn default_size(day: u8) -> Option<usize> {
+    SIZES
+        .iter()
+        .find(|&&(d, _, _)| d == day)
+        .map(|&(_, _, size)| size)
+}
+
+/// A random input for `day`, or `None` if the day has no generator.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    let rng = &mut Rng::new(seed);
+    let size = size.max(1);
+    Some(match day {
+        1 => lines(size, |_| {
+            format!(
+                "{}   {}",
+                rng.range(10_000..100_000),
+                rng.range(10_000..100_000)
+            )
+        }),
+        2 => lines(size, |_| report(rng)),
+        3 => memory(rng, size),
+        4 => grid(size, size, |_| *rng.pick(&['X', 'M', 'A', 'S'])),
+        5 => page_ordering(rng, size),
+        6 => guard_lab(rng, size),
+        7 => lines(size, |_| equation(rng)),
+        8 => antennas(rng, size),
+        9 => (0..size * 2 - 1)
+            .map(|i| match i % 2 {
+                0 => char::from(b'1' + rng.below(9) as u8),
+                _ => char::from(b'0' + rng.below(10) as u8),
+            })
+            .chain(['\n'])
+            .collect(),
+        // Mostly rising to the right and down, so there are trails to find.
+        10 => grid(size, size, |(row, col)| match rng.chance(0.2) {
+            true => char::from(b'0' + rng.below(10) as u8),
+            false => char::from(b'0' + ((row + col) % 10) as u8),
+        }),
+        11 => {
+            let stones: Vec<_> = (0..size)
+                .map(|_| rng.range(0..10_000_000).to_string())
+                .collect();
+            stones.join(" ") + "\n"
+        }
+        12 => garden(rng, size),
+        13 => claw_machines(rng, size),
+        14 => lines(size, |_| {
+            format!(
+                "p={},{} v={},{}",
+                rng.range(0..101),
+                rng.range(0..103),
+                rng.range(-100..101),
+                rng.range(-100..101)
+            )
+        }),
+        15 => warehouse(rng, size),
+        16 => maze(rng, size),
+        17 => program(rng, size),
+        18 => falling_bytes(rng, size),
+        19 => towels(rng, size),
+        _ => return None,
+    })
+}
+
+/// `n` lines from `f`, each ending in a newline.
+fn lines(n: usize, mut f: impl FnMut(usize) -> String) -> String {
+    (0..n).map(|i| f(i) + "\n").collect()
+}
+
+/// A `width` by `height` grid with cells from `f`, given their `(row, col)`.
+fn grid(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> char) -> String {
+    let mut out = String::with_capacity((width + 1) * height);
+    for row in 0..height {
+        out.extend((0..width).map(|col| f((row, col))));
+        out.push('\n');
+    }
+    out
+}
+
+fn report(rng: &mut Rng) -> String {
+    let step = if rng.chance(0.5) { 1 } else { -1 };
+    let mut level = rng.range(10..90);
+    let mut levels = Vec::new();
+    for _ in 0..rng.range(5..9) {
+        levels.push(level.to_string());
+        // Mostly safe steps, with the odd jump or turn.
+        level += match rng.below(10) {
+            0 => rng.range(-5..6),
+            _ => step * rng.range(1..4),
+        };
+    }
+    levels.join(" ")
+}
+
+fn memory(rng: &mut Rng, instructions: usize) -> String {
+    const NOISE: &[&str] = &[
+        "mul(4*",
+        "mul ( 2 , 4 )",
+        "?(",
+        "select()",
+        "from()",
+        "who()",
+        "don't",
+        "mul[3,7]",
+        "#",
+        "%&",
+        "where(536,162)",
+        ")",
+        "+",
+        "'",
+    ];
+    let mut out = String::new();
+    for _ in 0..instructions {
+        for _ in 0..rng.below(4) {
+            out.push_str(NOISE[rng.below(NOISE.len())]);
+        }
+        match rng.below(10) {
+            0 => out.push_str("do()"),
+            1 => out.push_str("don't()"),
+            _ => write!(out, "mul({},{})", rng.range(1..1000), rng.range(1..1000)).unwrap(),
+        }
+    }
+    out + "\n"
+}
+
+/// Every pair of pages is ordered by a rule, as in the real input.
+fn page_ordering(rng: &mut Rng, updates: usize) -> String {
+    let mut order: Vec<i64> = (10..100).collect();
+    shuffle(rng, &mut order);
+    order.truncate(49);
+
+    let mut out = String::new();
+    for (i, before) in order.iter().enumerate() {
+        for after in &order[i + 1..] {
+            writeln!(out, "{before}|{after}").unwrap();
+        }
+    }
+    out.push('\n');
+
+    let mut pages = order.clone();
+    for _ in 0..updates {
+        shuffle(rng, &mut pages);
+        let len = 2 * rng.range(2..12) as usize + 1;
+        let update = &mut pages[..len];
+        // About half the updates are already in order.
+        if rng.chance(0.5) {
+            update.sort_by_key(|page| order.iter().position(|p| p == page));
+        }
+        let update: Vec<_> = update.iter().map(i64::to_string).collect();
+        writeln!(out, "{}", update.join(",")).unwrap();
+    }
+    out
+}
+
+/// A lab the guard walks out of; part one never ends otherwise.
+fn guard_lab(rng: &mut Rng, side: usize) -> String {
+    let side = side.max(2);
+    loop {
+        let start = Point::from((rng.below(side), rng.below(side)));
+        let lab = Grid::from_vec(
+            side,
+            side,
+            (0..side * side).map(|_| rng.chance(0.05)).collect(),
+        );
+        if lab[start] || !guard_leaves(&lab, start) {
+            continue;
+        }
+
+        return grid(side, side, |pos| match pos {
+            _ if Point::from(pos) == start => '^',
+            _ if lab[pos] => '#',
+            _ => '.',
+        });
+    }
+}
+
+fn guard_leaves(lab: &Grid<bool>, start: Point) -> bool {
+    let (mut pos, mut dir) = (start, Direction::Up);
+    let mut seen = HashSet::new();
+    while seen.insert((pos, dir)) {
+        match lab.at(pos.step(dir)) {
+            None => return true,
+            Some(true) => dir = dir.turn_right(),
+            Some(false) => pos = pos.step(dir),
+        }
+    }
+    false
+}
+
+/// An equation that holds half the time.
+fn equation(rng: &mut Rng) -> String {
+    let nums: Vec<i64> = (0..rng.range(2..9)).map(|_| rng.range(1..100)).collect();
+    let target = if rng.chance(0.5) {
+        nums[1..]
+            .iter()
+            .fold(nums[0], |acc, &n| match rng.below(3) {
+                0 => acc + n,
+                1 => acc * n,
+                _ => format!("{acc}{n}").parse().unwrap_or(acc),
+            })
+    } else {
+        rng.range(1..1_000_000_000)
+    };
+    let nums: Vec<_> = nums.iter().map(i64::to_string).collect();
+    format!("{target}: {}", nums.join(" "))
+}
+
+fn antennas(rng: &mut Rng, side: usize) -> String {
+    let frequencies: Vec<char> = ('a'..='z').chain('A'..='Z').chain('0'..='9').collect();
+    grid(side, side, |_| match rng.chance(0.08) {
+        true => *rng.pick(&frequencies),
+        false => '.',
+    })
+}
+
+/// Patches of a few plants, so regions are bigger than single cells.
+fn garden(rng: &mut Rng, side: usize) -> String {
+    let plants: Vec<char> = ('A'..='Z').collect();
+    let mut rows: Vec<Vec<char>> = Vec::with_capacity(side);
+    for row in 0..side {
+        let mut cells = Vec::with_capacity(side);
+        for col in 0..side {
+            let plant = match rng.below(4) {
+                0 if row > 0 => rows[row - 1][col],
+                1 | 2 if col > 0 => cells[col - 1],
+                _ => *rng.pick(&plants),
+            };
+            cells.push(plant);
+        }
+        rows.push(cells);
+    }
+    grid(side, side, |(row, col)| rows[row][col])
+}
+
+/// Machines whose buttons are never parallel, as in the real input.
+fn claw_machines(rng: &mut Rng, machines: usize) -> String {
+    let mut out = Vec::with_capacity(machines);
+    while out.len() < machines {
+        let (x1, y1) = (rng.range(10..100), rng.range(10..100));
+        let (x2, y2) = (rng.range(10..100), rng.range(10..100));
+        if x1 * y2 == x2 * y1 {
+            continue;
+        }
+        let (xt, yt) = match rng.chance(0.5) {
+            true => {
+                let (a, b) = (rng.range(0..101), rng.range(0..101));
+                (a * x1 + b * x2, a * y1 + b * y2)
+            }
+            false => (rng.range(1000..20_000), rng.range(1000..20_000)),
+        };
+        out.push(format!(
+            "Button A: X+{x1}, Y+{y1}\nButton B: X+{x2}, Y+{y2}\nPrize: X={xt}, Y={yt}\n"
+        ));
+    }
+    out.join("\n")
+}
+
+fn warehouse(rng: &mut Rng, side: usize) -> String {
+    let side = side.max(3);
+    let robot = (rng.range(1..side as i64 - 1), rng.range(1..side as i64 - 1));
+    let mut out = grid(side, side, |(row, col)| {
+        let (row, col) = (row as i64, col as i64);
+        let edge = side as i64 - 1;
+        match (row, col) {
+            _ if (row, col) == robot => '@',
+            _ if row == 0 || col == 0 || row == edge || col == edge => '#',
+            _ if rng.chance(0.05) => '#',
+            _ if rng.chance(0.25) => 'O',
+            _ => '.',
+        }
+    });
+
+    out.push('\n');
+    let moves = side * side * 2;
+    for line in 0..moves.div_ceil(70) {
+        let len = 70.min(moves - line * 70);
+        out.extend((0..len).map(|_| *rng.pick(&['^', '>', 'v', '<'])));
+        out.push('\n');
+    }
+    out
+}
+
+/// A maze carved by a randomised depth-first search, with some extra walls
+/// knocked through so there is more than one way round.
+fn maze(rng: &mut Rng, side: usize) -> String {
+    // Rooms sit on odd coordinates, so the side must be odd.
+    let side = (side.max(5) - 1) / 2 * 2 + 1;
+    let mut open = vec![vec![false; side]; side];
+    let mut stack = vec![(side - 2, 1)];
+    open[side - 2][1] = true;
+
+    while let Some(&(row, col)) = stack.last() {
+        let mut next = Vec::new();
+        if row > 2 && !open[row - 2][col] {
+            next.push((row - 2, col));
+        }
+        if row + 2 < side && !open[row + 2][col] {
+            next.push((row + 2, col));
+        }
+        if col > 2 && !open[row][col - 2] {
+            next.push((row, col - 2));
+        }
+        if col + 2 < side && !open[row][col + 2] {
+            next.push((row, col + 2));
+        }
+
+        match next.is_empty() {
+            true => {
+                stack.pop();
+            }
+            false => {
+                let (r, c) = *rng.pick(&next);
+                open[(row + r) / 2][(col + c) / 2] = true;
+                open[r][c] = true;
+                stack.push((r, c));
+            }
+        }
+    }
+    for (row, cells) in open.iter_mut().enumerate().take(side - 1).skip(1) {
+        for (col, cell) in cells.iter_mut().enumerate().take(side - 1).skip(1) {
+            if (row + col) % 2 == 1 && rng.chance(0.1) {
+                *cell = true;
+            }
+        }
+    }
+
+    grid(side, side, |(row, col)| match (row, col) {
+        _ if (row, col) == (side - 2, 1) => 'S',
+        _ if (row, col) == (1, side - 2) => 'E',
+        _ if open[row][col] => '.',
+        _ => '#',
+    })
+}
+
+/// A program shaped like the real ones: scramble the low bits of A into B
+/// and C, output B, shift A right by three and loop until A is zero. It halts
+/// after one output per octal digit of A.
+fn program(rng: &mut Rng, digits: usize) -> String {
+    let digits = digits.clamp(1, 20) as u32;
+    let a = rng.range(8i64.pow(digits - 1)..8i64.pow(digits));
+
+    let mut body = vec![
+        vec![2, 4],
+        vec![1, rng.range(0..8)],
+        vec![7, 5],
+        vec![1, rng.range(0..8)],
+        vec![4, rng.range(0..8)],
+    ];
+    if rng.chance(0.5) {
+        body.swap(3, 4);
+    }
+    let program: Vec<String> = body
+        .into_iter()
+        .flatten()
+        .chain([0, 3, 5, 5, 3, 0])
+        .map(|n| n.to_string())
+        .collect();
+
+    format!(
+        "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+        program.join(",")
+    )
+}
+
+/// Distinct bytes on the 71x71 memory space, never on the start or exit.
+/// Day 18 reads the first 1024, so fewer are never generated.
+fn falling_bytes(rng: &mut Rng, bytes: usize) -> String {
+    let mut cells: Vec<(usize, usize)> = (0..71)
+        .flat_map(|x| (0..71).map(move |y| (x, y)))
+        .filter(|&cell| cell != (0, 0) && cell != (70, 70))
+        .collect();
+    shuffle(rng, &mut cells);
+    cells.truncate(bytes.max(1024));
+    lines(cells.len(), |i| format!("{},{}", cells[i].0, cells[i].1))
+}
+
+/// Towels and designs, half of which are made from the towels.
+fn towels(rng: &mut Rng, designs: usize) -> String {
+    const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+    let mut towels: Vec<String> = (0..rng.range(40..60))
+        .map(|_| (0..rng.range(1..9)).map(|_| *rng.pick(&COLORS)).collect())
+        .collect();
+    towels.sort();
+    towels.dedup();
+
+    let mut out = towels.join(", ") + "\n\n";
+    for _ in 0..designs {
+        let len = rng.range(20..61) as usize;
+        let mut design = String::new();
+        let made = rng.chance(0.5);
+        while design.len() < len {
+            match made {
+                true => design.push_str(&towels[rng.below(towels.len())]),
+                false => design.push(*rng.pick(&COLORS)),
+            }
+        }
+        out.push_str(&design);
+        out.push('\n');
+    }
+    out
+}
+
+fn shuffle<T>(rng: &mut Rng, items: &mut [T]) {
+    for i in (1..items.len()).rev() {
+        items.swap(i, rng.below(i + 1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{self, Outcome};
+    use crate::y2024::YEAR;
+
+    #[test]
+    fn every_day_parses_and_solves_part_one() {
+        for &solver in YEAR.days {
+            let day = solver.day();
+            for seed in 0..3 {
+                let input = generate(day, seed, 12).unwrap();
+                assert_eq!(generate(day, seed, 12).unwrap(), input, "day {day}");
+
+                let run = runner::run_day(solver, &input, Some(1));
+                assert!(
+                    matches!(run.part1, Outcome::Solved(_) | Outcome::Unimplemented),
+                    "day {day} seed {seed}: {:?}\n{input}",
+                    run.part1
+                );
+            }
+        }
+        assert_eq!(generate(25, 0, 12), None);
+    }
+}
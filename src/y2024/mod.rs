@@ -0,0 +1,82 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod gen;
+
+use crate::visualize::{Palette, Screen};
+use crate::years::{Animation, Draw, Year};
+use crate::Solver;
+
+pub static YEAR: Year = Year {
+    year: 2024,
+    days: &REGISTRY,
+    animation,
+    picture,
+    generate: gen::generate,
+    default_size: gen::default_size,
+};
+
+/// Every solved day, in order.
+pub static REGISTRY: [&dyn Solver; 19] = [
+    &day01::Day1,
+    &day02::Day2,
+    &day03::Day3,
+    &day04::Day4,
+    &day05::Day5,
+    &day06::Day6,
+    &day07::Day7,
+    &day08::Day8,
+    &day09::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+];
+
+fn animation(day: u8) -> Option<Animation> {
+    let (visualize, palette): (fn(&str, &mut dyn Screen) -> _, Palette) = match day {
+        6 => (day06::visualize, day06::palette),
+        9 => (day09::visualize, day09::palette),
+        14 => (day14::visualize, day14::palette),
+        15 => (day15::visualize, day15::palette),
+        _ => return None,
+    };
+    Some(Animation { visualize, palette })
+}
+
+fn picture(day: u8) -> Option<Draw> {
+    match day {
+        4 => Some(day04::picture),
+        6 => Some(day06::picture),
+        8 => Some(day08::picture),
+        10 => Some(day10::picture),
+        12 => Some(day12::picture),
+        14 => Some(day14::picture),
+        15 => Some(day15::picture),
+        16 => Some(day16::picture),
+        18 => Some(day18::picture),
+        _ => None,
+    }
+}
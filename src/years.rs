@@ -0,0 +1,76 @@
+// The event years this crate has solutions for. Each year is a `yNNNN`
+// module holding one `dayNN` module per solved day, and registers itself
+// here along with the extras its days provide: animations, pictures and
+// input generators.
+
+use crate::error::ParseError;
+use crate::image::Picture;
+use crate::visualize::{Palette, Screen};
+use crate::{y2024, Solver};
+
+/// Every year with solutions, oldest first.
+pub static YEARS: [&Year; 1] = [&y2024::YEAR];
+
+/// The latest year solved, which commands work on when `--year` is not given.
+pub const DEFAULT_YEAR: u16 = 2024;
+
+/// The first year the puzzles were posted.
+pub const FIRST_YEAR: u16 = 2015;
+
+pub struct Year {
+    pub year: u16,
+    /// Every solved day, in order.
+    pub days: &'static [&'static dyn Solver],
+    pub animation: fn(u8) -> Option<Animation>,
+    pub picture: fn(u8) -> Option<Draw>,
+    /// A random input for `(day, seed, size)`, if the day has a generator.
+    pub generate: fn(u8, u64, usize) -> Option<String>,
+    /// The size of a real input for a day, if it has a generator.
+    pub default_size: fn(u8) -> Option<usize>,
+}
+
+impl Year {
+    pub fn get(&self, day: u8) -> Option<&'static dyn Solver> {
+        self.days.iter().copied().find(|s| s.day() == day)
+    }
+
+    /// The year's module, as it appears in source paths and test names.
+    pub fn module(year: u16) -> String {
+        format!("y{year}")
+    }
+}
+
+pub fn get(year: u16) -> Option<&'static Year> {
+    YEARS.iter().copied().find(|y| y.year == year)
+}
+
+/// A day whose simulation can be watched with `aoc run <day> --visualize`.
+#[derive(Clone, Copy)]
+pub struct Animation {
+    /// Parse the input and send the simulation's frames to the screen.
+    pub visualize: fn(&str, &mut dyn Screen) -> Result<(), ParseError>,
+    pub palette: Palette,
+}
+
+/// Draws a day's puzzle state for `aoc run <day> --dump-image <path>`.
+pub type Draw = fn(&str) -> Result<Picture, ParseError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn years_and_days_are_in_order() {
+        assert!(YEARS.windows(2).all(|w| w[0].year < w[1].year));
+        assert!(get(DEFAULT_YEAR).is_some());
+        for year in YEARS {
+            assert!(year.year >= FIRST_YEAR);
+            assert!(
+                year.days.windows(2).all(|w| w[0].day() < w[1].day()),
+                "{}",
+                year.year
+            );
+            assert!(year.days.iter().all(|s| (1..=25).contains(&s.day())));
+        }
+    }
+}